@@ -1,21 +1,26 @@
 use super::{
-    config::{Config, ReprKind},
+    config::{Config, ConfigValue, ReprKind},
     field_config::{FieldConfig, SkipWhich},
-    raise_skip_error, BitfieldStruct,
+    is_bool_type, raise_skip_error, BitfieldStruct,
 };
+use crate::errors::CombineError;
 use core::convert::TryFrom;
-use quote::quote;
+use quote::{format_ident, quote, ToTokens as _};
+use std::collections::HashMap;
 use syn::{self, parse::Result, spanned::Spanned as _};
 
 impl TryFrom<(&mut Config, syn::ItemStruct)> for BitfieldStruct {
     type Error = syn::Error;
 
-    fn try_from((config, item_struct): (&mut Config, syn::ItemStruct)) -> Result<Self> {
+    fn try_from((config, mut item_struct): (&mut Config, syn::ItemStruct)) -> Result<Self> {
         Self::ensure_has_fields(&item_struct)?;
         Self::ensure_valid_generics(&item_struct)?;
         Self::extract_attributes(&item_struct.attrs, config)?;
-        Self::analyse_config_for_fields(&item_struct, config)?;
+        Self::analyse_config_for_fields(&mut item_struct, config)?;
         config.ensure_no_conflicts()?;
+        Self::ensure_valid_transparent(&item_struct, config)?;
+        Self::ensure_valid_groups(&item_struct, config)?;
+        Self::ensure_valid_positions(&item_struct, config)?;
         Ok(Self { item_struct })
     }
 }
@@ -35,6 +40,117 @@ impl BitfieldStruct {
         Ok(())
     }
 
+    /// Returns an error if `transparent = true` is used on a struct without exactly one field.
+    fn ensure_valid_transparent(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        if let Some(transparent @ ConfigValue { value: true, .. }) = config.transparent.as_ref() {
+            let len = item_struct.fields.len();
+            if len != 1 {
+                return Err(format_err!(
+                    transparent.span,
+                    "encountered `transparent = true` on bitfield struct with {} fields but expected exactly one field",
+                    len,
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if the fields of a `#[group(Name)]` are not adjacent, belong to
+    /// a tuple struct or to a `fallible = true` bitfield.
+    fn ensure_valid_groups(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        let mut finished = Vec::<&syn::Ident>::new();
+        let mut current: Option<&syn::Ident> = None;
+        for (index, field) in Self::fields(item_struct) {
+            let group = config
+                .field_configs
+                .get(&index)
+                .and_then(|field_config| field_config.value.group.as_ref());
+            let Some(group) = group else {
+                finished.extend(current.take());
+                continue;
+            };
+            if field.ident.is_none() {
+                return Err(format_err!(
+                    group.span,
+                    "encountered #[group(Name)] on a field of a tuple struct"
+                ));
+            }
+            if config.fallible_enabled() {
+                return Err(format_err!(
+                    group.span,
+                    "encountered #[group(Name)] on a field of a `fallible = true` bitfield"
+                ));
+            }
+            if current == Some(&group.value) {
+                continue;
+            }
+            if finished.contains(&&group.value) {
+                return Err(format_err!(
+                    group.span,
+                    "encountered non-adjacent field of #[group({})]",
+                    group.value
+                ));
+            }
+            finished.extend(current.replace(&group.value));
+        }
+        Ok(())
+    }
+
+    /// Returns an error if some but not all fields have a `#[at(bit = N)]` attribute,
+    /// if two fields are placed at the same bit or if no field is placed at bit 0.
+    ///
+    /// Gaps and overlaps between fields are checked at compile time of the generated code
+    /// since the bit widths of the fields are not known to the macro.
+    fn ensure_valid_positions(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        let positions = Self::fields(item_struct)
+            .map(|(index, field)| {
+                let at = config
+                    .field_configs
+                    .get(&index)
+                    .and_then(|field_config| field_config.value.at.as_ref());
+                (field, at)
+            })
+            .collect::<Vec<_>>();
+        let Some(first) = positions.iter().find_map(|(_, at)| *at) else {
+            return Ok(());
+        };
+        let mut seen = HashMap::<usize, &ConfigValue<usize>>::new();
+        for (field, at) in &positions {
+            let Some(at) = at else {
+                return Err(format_err_spanned!(
+                    field,
+                    "encountered field without #[at(bit = N)] in a bitfield placing other fields explicitly"
+                )
+                .into_combine(format_err!(first.span, "explicitly placed field here")));
+            };
+            if let Some(previous) = seen.insert(at.value, at) {
+                return Err(format_err!(
+                    at.span,
+                    "encountered duplicate #[at(bit = {})] for multiple fields",
+                    at.value
+                )
+                .into_combine(format_err!(
+                    previous.span,
+                    "duplicate #[at(bit = {})] here",
+                    at.value
+                )));
+            }
+        }
+        if let Some(lowest) = positions
+            .iter()
+            .filter_map(|(_, at)| *at)
+            .min_by_key(|at| at.value)
+            .filter(|at| at.value != 0)
+        {
+            return Err(format_err!(
+                lowest.span,
+                "encountered no field at bit 0 but the first field starts at bit {}",
+                lowest.value
+            ));
+        }
+        Ok(())
+    }
+
     /// Returns an error if the input struct contains generics that cannot be
     /// used in a const expression.
     fn ensure_valid_generics(item_struct: &syn::ItemStruct) -> Result<()> {
@@ -66,10 +182,17 @@ impl BitfieldStruct {
             } else if path.is_ident("u128") {
                 Some(ReprKind::U128)
             } else {
-                // If other repr such as `transparent` or `C` have been found we
-                // are going to re-expand them into a new `#[repr(..)]` that is
-                // ignored by the rest of this macro.
-                retained_reprs.push(path.clone());
+                // If other repr such as `transparent`, `C` or `align(N)` have been
+                // found we are going to re-expand them into a new `#[repr(..)]` that
+                // is ignored by the rest of this macro.
+                if meta.input.peek(syn::token::Paren) {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let args = content.parse::<proc_macro2::TokenStream>()?;
+                    retained_reprs.push(quote! { #path(#args) });
+                } else {
+                    retained_reprs.push(path.to_token_stream());
+                }
                 None
             };
             if let Some(repr_kind) = repr_kind {
@@ -97,7 +220,8 @@ impl BitfieldStruct {
         Ok(())
     }
 
-    /// Extracts the `#[derive(Debug)]` annotations from the given `#[bitfield]` struct.
+    /// Extracts the `#[derive(Debug)]`, `#[derive(Specifier)]` and `#[derive(PartialEq)]`
+    /// annotations from the given `#[bitfield]` struct.
     fn extract_derive_debug_attribute(attr: &syn::Attribute, config: &mut Config) -> Result<()> {
         let list = attr.meta.require_list()?;
         let mut retained_derives = vec![];
@@ -107,6 +231,8 @@ impl BitfieldStruct {
                 config.derive_debug(path.span())?;
             } else if path.is_ident("Specifier") {
                 config.derive_specifier(path.span())?;
+            } else if path.is_ident("PartialEq") {
+                config.derive_partial_eq(path.span())?;
             } else {
                 // Other derives are going to be re-expanded them into a new
                 // `#[derive(..)]` that is ignored by the rest of this macro.
@@ -149,36 +275,101 @@ impl BitfieldStruct {
     }
 
     /// Analyses and extracts the configuration for all bitfield fields.
-    fn analyse_config_for_fields(item_struct: &syn::ItemStruct, config: &mut Config) -> Result<()> {
-        for (index, field) in Self::fields(item_struct) {
+    ///
+    /// The `()` type of `#[padding(N)]` fields is replaced by the `BN` specifier.
+    fn analyse_config_for_fields(
+        item_struct: &mut syn::ItemStruct,
+        config: &mut Config,
+    ) -> Result<()> {
+        for (index, field) in item_struct.fields.iter_mut().enumerate() {
             let span = field.span();
-            let field_config = Self::extract_field_config(field)?;
+            let mut field_config = Self::extract_field_config(field)?;
+            Self::expand_padding_field(field, &mut field_config)?;
             config.field_config(index, span, field_config)?;
         }
         Ok(())
     }
 
-    /// Extracts the `#[bits = N]` and `#[skip(..)]` attributes for a given field.
+    /// Replaces the `()` type of a `#[padding(N)]` field by the `BN` specifier and skips
+    /// all of its getters and setters.
+    ///
+    /// # Errors
+    ///
+    /// - If a `#[padding(N)]` field is not of type `()` or `N` is not within `1..=128`.
+    /// - If a field of type `()` has no `#[padding(N)]` attribute.
+    fn expand_padding_field(field: &mut syn::Field, field_config: &mut FieldConfig) -> Result<()> {
+        let is_unit = matches!(&field.ty, syn::Type::Tuple(tuple) if tuple.elems.is_empty());
+        let Some(padding) = field_config.padding.clone() else {
+            if is_unit {
+                return Err(format_err_spanned!(
+                    field.ty,
+                    "encountered `()` field without #[padding(N)] attribute"
+                ));
+            }
+            return Ok(());
+        };
+        if !is_unit {
+            return Err(format_err_spanned!(
+                field.ty,
+                "encountered #[padding(N)] field with non-`()` type"
+            ));
+        }
+        if !(1..=128).contains(&padding.value) {
+            return Err(format_err!(
+                padding.span,
+                "encountered #[padding({})] but padding must be within 1..=128 bits",
+                padding.value
+            ));
+        }
+        let specifier = format_ident!("B{}", padding.value, span = padding.span);
+        field.ty =
+            syn::parse_quote_spanned!(padding.span=> ::modular_bitfield::specifiers::#specifier);
+        field_config.inherit_skip(SkipWhich::All, padding.span);
+        Ok(())
+    }
+
+    /// Extracts the `#[bits = N]`, `#[padding(N)]`, `#[group(Name)]`, `#[at(bit = N)]`,
+    /// `#[expect_value = value]`, `#[bit_reverse]`, `#[signed]` and `#[skip(..)]` attributes for a
+    /// given field.
     fn extract_field_config(field: &syn::Field) -> Result<FieldConfig> {
         let mut config = FieldConfig::default();
         for attr in &field.attrs {
             if attr.path().is_ident("bits") {
-                let name_value = attr.meta.require_name_value()?;
-                let span = name_value.span();
-                match &name_value.value {
-                    syn::Expr::Lit(syn::ExprLit {
-                        lit: syn::Lit::Int(lit_int),
-                        ..
-                    }) => {
-                        config.bits(lit_int.base10_parse::<usize>()?, span)?;
-                    }
-                    value => {
-                        return Err(format_err!(
-                            value.span(),
-                            "encountered invalid value type for #[bits = N]"
-                        ))
-                    }
-                }
+                Self::extract_bits_attribute(field, attr, &mut config)?;
+            } else if attr.path().is_ident("padding") {
+                let meta_list = attr.meta.require_list()?;
+                let lit_int = meta_list.parse_args::<syn::LitInt>().map_err(|_| {
+                    format_err!(
+                        meta_list.tokens.span(),
+                        "encountered invalid value type for #[padding(N)]"
+                    )
+                })?;
+                config.padding(lit_int.base10_parse::<usize>()?, meta_list.path.span())?;
+            } else if attr.path().is_ident("group") {
+                let meta_list = attr.meta.require_list()?;
+                let name = meta_list.parse_args::<syn::Ident>().map_err(|_| {
+                    format_err!(
+                        meta_list.tokens.span(),
+                        "encountered invalid value type for #[group(Name)]"
+                    )
+                })?;
+                config.group(name, meta_list.path.span())?;
+            } else if attr.path().is_ident("at") {
+                Self::extract_at_attribute(attr, &mut config)?;
+            } else if attr.path().is_ident("expect_value") {
+                let name_value = attr.meta.require_name_value().map_err(|_| {
+                    format_err_spanned!(
+                        attr,
+                        "encountered invalid format for #[expect_value = value]"
+                    )
+                })?;
+                config.expect_value(name_value.value.clone(), name_value.span())?;
+            } else if attr.path().is_ident("bit_reverse") {
+                let path = attr.meta.require_path_only()?;
+                config.bit_reverse(path.span())?;
+            } else if attr.path().is_ident("signed") {
+                let path = attr.meta.require_path_only()?;
+                config.signed(path.span())?;
             } else if attr.path().is_ident("skip") {
                 match &attr.meta {
                     syn::Meta::Path(path) => {
@@ -227,4 +418,62 @@ impl BitfieldStruct {
         }
         Ok(config)
     }
+
+    /// Extracts the bit width of a `#[bits = N]` field attribute.
+    fn extract_bits_attribute(
+        field: &syn::Field,
+        attr: &syn::Attribute,
+        config: &mut FieldConfig,
+    ) -> Result<()> {
+        let name_value = attr.meta.require_name_value()?;
+        let span = name_value.span();
+        match &name_value.value {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(lit_int),
+                ..
+            }) => {
+                let bits = lit_int.base10_parse::<usize>()?;
+                if bits != 1 && is_bool_type(&field.ty) {
+                    return Err(format_err!(
+                        span,
+                        "encountered #[bits = {}] on a `bool` field: \
+                         bool fields are always 1 bit; remove the #[bits] attribute",
+                        bits
+                    ));
+                }
+                config.bits(bits, span)?;
+            }
+            value => {
+                return Err(format_err!(
+                    value.span(),
+                    "encountered invalid value type for #[bits = N]"
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Extracts the bit offset of a `#[at(bit = N)]` field attribute.
+    fn extract_at_attribute(attr: &syn::Attribute, config: &mut FieldConfig) -> Result<()> {
+        let meta_list = attr.meta.require_list()?;
+        let mut bit = None;
+        meta_list.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("bit") {
+                return Err(meta.error("encountered unknown or unsupported #[at(..)] specifier"));
+            }
+            let lit_int = meta
+                .value()?
+                .parse::<syn::LitInt>()
+                .map_err(|_| meta.error("encountered invalid value type for #[at(bit = N)]"))?;
+            bit = Some(lit_int.base10_parse::<usize>()?);
+            Ok(())
+        })?;
+        let Some(bit) = bit else {
+            return Err(format_err!(
+                meta_list.span(),
+                "encountered #[at(..)] without `bit = N`"
+            ));
+        };
+        config.at(bit, meta_list.path.span())
+    }
 }
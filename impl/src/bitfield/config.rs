@@ -1,19 +1,41 @@
-use super::field_config::FieldConfig;
+use super::{expand::to_snake_case, field_config::FieldConfig};
 use crate::errors::CombineError;
 use core::any::TypeId;
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::ToTokens;
 use std::collections::{hash_map::Entry, HashMap};
 use syn::parse::Result;
+use syn::{ext::IdentExt as _, spanned::Spanned as _};
 
 /// The configuration for the `#[bitfield]` macro.
 #[derive(Default)]
 pub struct Config {
     pub bytes: Option<ConfigValue<usize>>,
-    pub bits: Option<ConfigValue<usize>>,
+    pub bits: Option<ConfigValue<BitsValue>>,
     pub filled: Option<ConfigValue<bool>>,
+    pub transparent: Option<ConfigValue<bool>>,
+    pub fallible: Option<ConfigValue<bool>>,
+    pub getters: Option<ConfigValue<bool>>,
+    pub setters: Option<ConfigValue<bool>>,
+    pub allow: Vec<syn::Path>,
+    pub track_caller: Option<ConfigValue<()>>,
+    pub warn_waste: Option<ConfigValue<()>>,
+    pub pad_to_pow2: Option<ConfigValue<()>>,
+    pub doc_layout: Option<ConfigValue<()>>,
+    pub copy: Option<ConfigValue<()>>,
+    pub index: Option<ConfigValue<()>>,
+    pub prop_test: Option<ConfigValue<()>>,
+    pub field_trait: Option<ConfigValue<()>>,
+    pub field_layout: Option<ConfigValue<()>>,
+    pub set_masked: Option<ConfigValue<()>>,
+    pub raw_setters: Option<ConfigValue<()>>,
+    pub ref_getters: Option<ConfigValue<()>>,
+    pub as_getters: Option<ConfigValue<()>>,
+    pub rename_accessors: Option<ConfigValue<RenameAccessors>>,
     pub repr: Option<ConfigValue<ReprKind>>,
     pub derive_debug: Option<ConfigValue<()>>,
     pub derive_specifier: Option<ConfigValue<()>>,
+    pub derive_partial_eq: Option<ConfigValue<()>>,
     pub retained_attributes: Vec<syn::Attribute>,
     pub field_configs: HashMap<usize, ConfigValue<FieldConfig>>,
 }
@@ -52,6 +74,76 @@ impl core::fmt::Debug for ReprKind {
     }
 }
 
+/// The case the accessor names are converted to by the `rename_accessors` #[bitfield] parameter.
+#[derive(Copy, Clone)]
+pub enum RenameAccessors {
+    /// Found `rename_accessors = "snake"`.
+    Snake,
+}
+
+impl RenameAccessors {
+    /// Returns the identifier of the given field identifier converted to this case.
+    pub fn apply(self, ident: &syn::Ident) -> syn::Ident {
+        match self {
+            Self::Snake => {
+                let snake = to_snake_case(&ident.unraw().to_string());
+                if syn::parse_str::<syn::Ident>(&snake).is_ok() {
+                    syn::Ident::new(&snake, ident.span())
+                } else {
+                    syn::Ident::new_raw(&snake, ident.span())
+                }
+            }
+        }
+    }
+}
+
+impl core::fmt::Debug for RenameAccessors {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Snake => write!(f, "\"snake\""),
+        }
+    }
+}
+
+/// The value of the `bits = N` #[bitfield] parameter.
+pub enum BitsValue {
+    /// Found an integer literal, e.g. `bits = 32`.
+    Int(usize),
+    /// Found a constant expression, e.g. `bits = MY_BITS`.
+    ///
+    /// Conflicts with other parameters are checked by the generated code.
+    Expr(syn::Expr),
+    /// Found a range, e.g. `bits = 16..=32`.
+    ///
+    /// Only restricts the sum of all field bits instead of fixing the bit width.
+    Range(syn::ExprRange),
+}
+
+impl core::fmt::Debug for BitsValue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Int(value) => write!(f, "{value}"),
+            Self::Expr(expr) => write!(f, "{}", expr.to_token_stream()),
+            Self::Range(range) => write!(f, "{}", range.to_token_stream()),
+        }
+    }
+}
+
+impl ToTokens for BitsValue {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match self {
+            Self::Int(value) => value.to_tokens(tokens),
+            // Parenthesized to preserve precedence when used within other expressions.
+            Self::Expr(expr) => {
+                syn::token::Paren(expr.span()).surround(tokens, |tokens| expr.to_tokens(tokens));
+            }
+            Self::Range(range) => {
+                syn::token::Paren(range.span()).surround(tokens, |tokens| range.to_tokens(tokens));
+            }
+        }
+    }
+}
+
 /// A configuration value and its originating span.
 #[derive(Clone)]
 pub struct ConfigValue<T> {
@@ -69,22 +161,38 @@ impl<T> ConfigValue<T> {
 }
 
 impl Config {
-    /// Returns the value of the `filled` parameter if provided and otherwise `true`.
+    /// Returns the value of the `filled` parameter if provided and otherwise `true`
+    /// unless the `pad_to_pow2` flag is set.
     pub fn filled_enabled(&self) -> bool {
-        self.filled.as_ref().map_or(true, |config| config.value)
+        self.filled
+            .as_ref()
+            .map_or(self.pad_to_pow2.is_none(), |config| config.value)
+    }
+
+    /// Returns the value of the `transparent` parameter if provided and otherwise `false`.
+    pub fn transparent_enabled(&self) -> bool {
+        self.transparent.as_ref().is_some_and(|config| config.value)
+    }
+
+    /// Returns the value of the `fallible` parameter if provided and otherwise `false`.
+    pub fn fallible_enabled(&self) -> bool {
+        self.fallible.as_ref().is_some_and(|config| config.value)
     }
 
     fn ensure_no_bits_and_repr_conflict(&self) -> Result<()> {
         if let (Some(bits), Some(repr)) = (self.bits.as_ref(), self.repr.as_ref()) {
-            if bits.value != repr.value.bits() {
+            let BitsValue::Int(bits_value) = bits.value else {
+                return Ok(());
+            };
+            if bits_value != repr.value.bits() {
                 return Err(format_err!(
                     Span::call_site(),
                     "encountered conflicting `bits = {}` and {:?} parameters",
-                    bits.value,
+                    bits_value,
                     repr.value,
                 )
                 .into_combine(
-                    format_err!(bits.span, "conflicting `bits = {}` here", bits.value,)
+                    format_err!(bits.span, "conflicting `bits = {}` here", bits_value,)
                         .into_combine(format_err!(repr.span, "conflicting {:?} here", repr.value)),
                 ));
             }
@@ -97,17 +205,24 @@ impl Config {
             fn next_div_by_8(value: usize) -> usize {
                 ((value.saturating_sub(1) / 8) + 1) * 8
             }
-            if next_div_by_8(bits.value) / 8 != bytes.value {
+            let BitsValue::Int(bits_value) = bits.value else {
+                return Ok(());
+            };
+            let required_bytes = next_div_by_8(bits_value) / 8;
+            if required_bytes != bytes.value {
                 return Err(format_err!(
                     Span::call_site(),
-                    "encountered conflicting `bits = {}` and `bytes = {}` parameters",
-                    bits.value,
+                    "encountered conflicting `bits = {}` and `bytes = {}` parameters: \
+                     {} bits require exactly {} bytes",
+                    bits_value,
                     bytes.value,
+                    bits_value,
+                    required_bytes,
                 )
                 .into_combine(format_err!(
                     bits.span,
                     "conflicting `bits = {}` here",
-                    bits.value
+                    bits_value
                 ))
                 .into_combine(format_err!(
                     bytes.span,
@@ -143,11 +258,108 @@ impl Config {
         Ok(())
     }
 
+    /// Ensures that `pad_to_pow2` is not combined with parameters that fix the bit width
+    /// or require all bits to be defined.
+    fn ensure_no_pad_to_pow2_conflict(&self) -> Result<()> {
+        let Some(pad_to_pow2) = self.pad_to_pow2.as_ref() else {
+            return Ok(());
+        };
+        let conflict = if let Some(bits) = self.bits.as_ref() {
+            Some((bits.span, format!("bits = {:?}", bits.value)))
+        } else if let Some(repr) = self.repr.as_ref() {
+            Some((repr.span, format!("{:?}", repr.value)))
+        } else {
+            self.filled
+                .as_ref()
+                .filter(|filled| filled.value)
+                .map(|filled| (filled.span, String::from("filled = true")))
+        };
+        if let Some((span, conflict)) = conflict {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `pad_to_pow2` and `{}` parameters",
+                conflict,
+            )
+            .into_combine(format_err!(
+                pad_to_pow2.span,
+                "conflicting `pad_to_pow2` here"
+            ))
+            .into_combine(format_err!(span, "conflicting `{}` here", conflict)));
+        }
+        Ok(())
+    }
+
+    fn ensure_no_transparent_and_fallible_conflict(&self) -> Result<()> {
+        if let (
+            Some(transparent @ ConfigValue { value: true, .. }),
+            Some(fallible @ ConfigValue { value: true, .. }),
+        ) = (self.transparent.as_ref(), self.fallible.as_ref())
+        {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `transparent = true` and `fallible = true` parameters: \
+                 transparent conversions require panicking accessors",
+            )
+            .into_combine(format_err!(
+                transparent.span,
+                "conflicting `transparent = true` here"
+            ))
+            .into_combine(format_err!(
+                fallible.span,
+                "conflicting `fallible = true` here"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Ensures that the `copy` flag is not combined with a `#[derive(Clone)]` or
+    /// `#[derive(Copy)]` of the struct since both would be derived twice.
+    fn ensure_no_copy_and_derive_conflict(&self) -> Result<()> {
+        let Some(copy) = self.copy.as_ref() else {
+            return Ok(());
+        };
+        for attr in &self.retained_attributes {
+            if !attr.path().is_ident("derive") {
+                continue;
+            }
+            let mut conflict = None;
+            attr.parse_nested_meta(|meta| {
+                let is_clone_or_copy = meta
+                    .path
+                    .segments
+                    .last()
+                    .is_some_and(|segment| segment.ident == "Clone" || segment.ident == "Copy");
+                if is_clone_or_copy && conflict.is_none() {
+                    conflict = Some(meta.path);
+                }
+                Ok(())
+            })?;
+            if let Some(path) = conflict {
+                let derive = path.to_token_stream().to_string().replace(' ', "");
+                return Err(format_err!(
+                    Span::call_site(),
+                    "encountered conflicting `copy` parameter and `#[derive({})]`",
+                    derive,
+                )
+                .into_combine(format_err!(copy.span, "conflicting `copy` here"))
+                .into_combine(format_err!(
+                    path,
+                    "conflicting `{}` derive here",
+                    derive
+                )));
+            }
+        }
+        Ok(())
+    }
+
     /// Ensures that there are no conflicting configuration parameters.
     pub fn ensure_no_conflicts(&self) -> Result<()> {
         self.ensure_no_bits_and_repr_conflict()?;
         self.ensure_no_bits_and_bytes_conflict()?;
         self.ensure_no_repr_and_filled_conflict()?;
+        self.ensure_no_transparent_and_fallible_conflict()?;
+        self.ensure_no_pad_to_pow2_conflict()?;
+        self.ensure_no_copy_and_derive_conflict()?;
         Ok(())
     }
 
@@ -191,7 +403,7 @@ impl Config {
     /// # Errors
     ///
     /// If the specifier has already been set.
-    pub fn bits(&mut self, value: usize, span: Span) -> Result<()> {
+    pub fn bits(&mut self, value: BitsValue, span: Span) -> Result<()> {
         match &self.bits {
             Some(previous) => return Err(Self::raise_duplicate_error("bits", span, previous)),
             None => self.bits = Some(ConfigValue::new(value, span)),
@@ -212,6 +424,273 @@ impl Config {
         Ok(())
     }
 
+    /// Sets the `transparent: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn transparent(&mut self, value: bool, span: Span) -> Result<()> {
+        match &self.transparent {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("transparent", span, previous))
+            }
+            None => self.transparent = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `fallible: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn fallible(&mut self, value: bool, span: Span) -> Result<()> {
+        match &self.fallible {
+            Some(previous) => return Err(Self::raise_duplicate_error("fallible", span, previous)),
+            None => self.fallible = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `getters: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn getters(&mut self, value: bool, span: Span) -> Result<()> {
+        match &self.getters {
+            Some(previous) => return Err(Self::raise_duplicate_error("getters", span, previous)),
+            None => self.getters = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `setters: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn setters(&mut self, value: bool, span: Span) -> Result<()> {
+        match &self.setters {
+            Some(previous) => return Err(Self::raise_duplicate_error("setters", span, previous)),
+            None => self.setters = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Adds the lints of an `allow: str` #[bitfield] parameter.
+    pub fn allow(&mut self, lints: impl IntoIterator<Item = syn::Path>) {
+        self.allow.extend(lints);
+    }
+
+    /// Registers the `track_caller` #[bitfield] flag parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn track_caller(&mut self, span: Span) -> Result<()> {
+        match &self.track_caller {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("track_caller", span, previous))
+            }
+            None => self.track_caller = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Registers the `warn_waste` #[bitfield] flag parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn warn_waste(&mut self, span: Span) -> Result<()> {
+        match &self.warn_waste {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("warn_waste", span, previous))
+            }
+            None => self.warn_waste = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Registers the `pad_to_pow2` #[bitfield] flag parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn pad_to_pow2(&mut self, span: Span) -> Result<()> {
+        match &self.pad_to_pow2 {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("pad_to_pow2", span, previous))
+            }
+            None => self.pad_to_pow2 = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Registers the `doc_layout` #[bitfield] flag parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn doc_layout(&mut self, span: Span) -> Result<()> {
+        match &self.doc_layout {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("doc_layout", span, previous))
+            }
+            None => self.doc_layout = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Registers the `copy` #[bitfield] flag parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn copy(&mut self, span: Span) -> Result<()> {
+        match &self.copy {
+            Some(previous) => return Err(Self::raise_duplicate_error("copy", span, previous)),
+            None => self.copy = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Registers the `index` #[bitfield] flag parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn index(&mut self, span: Span) -> Result<()> {
+        match &self.index {
+            Some(previous) => return Err(Self::raise_duplicate_error("index", span, previous)),
+            None => self.index = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Registers the `prop_test` #[bitfield] flag parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn prop_test(&mut self, span: Span) -> Result<()> {
+        match &self.prop_test {
+            Some(previous) => return Err(Self::raise_duplicate_error("prop_test", span, previous)),
+            None => self.prop_test = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Registers the `field_trait` #[bitfield] flag parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn field_trait(&mut self, span: Span) -> Result<()> {
+        match &self.field_trait {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("field_trait", span, previous))
+            }
+            None => self.field_trait = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Registers the `field_layout` #[bitfield] flag parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn field_layout(&mut self, span: Span) -> Result<()> {
+        match &self.field_layout {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("field_layout", span, previous))
+            }
+            None => self.field_layout = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Registers the `set_masked` #[bitfield] flag parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn set_masked(&mut self, span: Span) -> Result<()> {
+        match &self.set_masked {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("set_masked", span, previous))
+            }
+            None => self.set_masked = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Registers the `raw_setters` #[bitfield] flag parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn raw_setters(&mut self, span: Span) -> Result<()> {
+        match &self.raw_setters {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("raw_setters", span, previous))
+            }
+            None => self.raw_setters = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Registers the `ref_getters` #[bitfield] flag parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn ref_getters(&mut self, span: Span) -> Result<()> {
+        match &self.ref_getters {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("ref_getters", span, previous))
+            }
+            None => self.ref_getters = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Registers the `as_getters` #[bitfield] flag parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn as_getters(&mut self, span: Span) -> Result<()> {
+        match &self.as_getters {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("as_getters", span, previous))
+            }
+            None => self.as_getters = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Registers the `rename_accessors` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the parameter has already been set.
+    pub fn rename_accessors(&mut self, value: RenameAccessors, span: Span) -> Result<()> {
+        match &self.rename_accessors {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error(
+                    "rename_accessors",
+                    span,
+                    previous,
+                ))
+            }
+            None => self.rename_accessors = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
     /// Registers the `#[repr(uN)]` attribute for the #[bitfield] macro.
     ///
     /// # Errors
@@ -265,6 +744,25 @@ impl Config {
         Ok(())
     }
 
+    /// Registers the `#[derive(PartialEq)]` attribute for the #[bitfield] macro.
+    ///
+    /// # Errors
+    ///
+    /// If a `#[derive(PartialEq)]` attribute has already been found.
+    pub fn derive_partial_eq(&mut self, span: Span) -> Result<()> {
+        match &self.derive_partial_eq {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error(
+                    "#[derive(PartialEq)]",
+                    span,
+                    previous,
+                ))
+            }
+            None => self.derive_partial_eq = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
     /// Pushes another retained attribute that the #[bitfield] macro is going to re-expand and ignore.
     pub fn push_retained_attribute(&mut self, retained_attr: syn::Attribute) {
         self.retained_attributes.push(retained_attr);
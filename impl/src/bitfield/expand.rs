@@ -1,38 +1,109 @@
 use super::{
-    config::{Config, ReprKind},
+    config::{BitsValue, Config, ConfigValue, ReprKind},
     field_info::FieldInfo,
-    BitfieldStruct,
+    is_bool_type, BitfieldStruct,
 };
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::{format_ident, quote_spanned, ToTokens};
-use syn::{self, punctuated::Punctuated, spanned::Spanned as _, Token};
+use syn::{self, ext::IdentExt as _, punctuated::Punctuated, spanned::Spanned as _, Token};
 
 impl BitfieldStruct {
     /// Expands the given `#[bitfield]` struct into an actual bitfield definition.
     pub fn expand(&self, config: &Config) -> TokenStream2 {
         let span = self.item_struct.span();
         let check_filled = self.generate_check_for_filled(config);
+        let check_waste = self.expand_waste_check(config);
         let struct_definition = self.generate_struct(config);
         let constructor_definition = self.generate_constructor(config);
         let specifier_impl = self.generate_specifier_impl(config);
 
         let byte_conversion_impls = self.expand_byte_conversion_impls(config);
+        let as_bytes_impls = self.expand_as_bytes_impls(config);
+        let from_iter_impl = self.expand_from_iter_impl();
+        let index_impl = self.expand_index_impl(config);
+        let parse_prefix = self.expand_parse_prefix(config);
+        let io_methods = self.expand_io_methods(config);
+        let atomic_methods = self.expand_atomic_methods(config);
+        let eq_bytes_impl = self.expand_eq_bytes_impl(config);
+        let primitive_conversions = self.expand_primitive_conversions(config);
+        let passthrough_conversions = self.expand_passthrough_conversions(config);
         let getters_and_setters = self.expand_getters_and_setters(config);
+        let field_trait_impls = self.expand_field_trait_impls(config);
+        let group_accessors = self.expand_group_accessors(config);
         let bytes_check = self.expand_optional_bytes_check(config);
         let repr_impls_and_checks = self.expand_repr_from_impls_and_checks(config);
         let debug_impl = self.generate_debug_impl(config);
+        let fields_debug = self.generate_fields_debug(config);
+        let checked_into_bytes = self.expand_checked_into_bytes(config);
+        let layout_const = self.expand_layout_const(config);
+        let field_layout = self.expand_field_layout(config);
+        let get_all = self.expand_get_all(config);
+        let from_fields = self.expand_from_fields(config);
+        let round_trip_check = self.expand_round_trip_check(config);
 
-        quote_spanned!(span=>
+        let expanded = quote_spanned!(span=>
             #struct_definition
             #check_filled
+            #check_waste
             #constructor_definition
             #byte_conversion_impls
+            #as_bytes_impls
+            #from_iter_impl
+            #index_impl
+            #parse_prefix
+            #io_methods
+            #atomic_methods
+            #eq_bytes_impl
+            #primitive_conversions
+            #passthrough_conversions
             #getters_and_setters
+            #field_trait_impls
+            #group_accessors
             #specifier_impl
             #bytes_check
             #repr_impls_and_checks
             #debug_impl
-        )
+            #fields_debug
+            #checked_into_bytes
+            #layout_const
+            #field_layout
+            #get_all
+            #from_fields
+            #round_trip_check
+        );
+        Self::apply_allow_attributes(expanded, config)
+    }
+
+    /// Adds `#[allow(..)]` with the lints of the `allow` parameter to all generated items.
+    fn apply_allow_attributes(expanded: TokenStream2, config: &Config) -> TokenStream2 {
+        if config.allow.is_empty() {
+            return expanded;
+        }
+        let mut file = match syn::parse2::<syn::File>(expanded) {
+            Ok(file) => file,
+            // Silently dropping the lints would resurface the warnings they are meant to allow.
+            Err(error) => {
+                return format_err!(
+                    Span::call_site(),
+                    "failed to apply the `allow` parameter to the generated items: {}",
+                    error,
+                )
+                .to_compile_error()
+            }
+        };
+        let lints = &config.allow;
+        let allow: syn::Attribute = syn::parse_quote!(#[allow( #( #lints ),* )]);
+        for item in &mut file.items {
+            let attrs = match item {
+                syn::Item::Struct(item) => &mut item.attrs,
+                syn::Item::Impl(item) => &mut item.attrs,
+                syn::Item::Const(item) => &mut item.attrs,
+                syn::Item::Mod(item) => &mut item.attrs,
+                _ => continue,
+            };
+            attrs.push(allow.clone());
+        }
+        file.into_token_stream()
     }
 
     /// Expands to the `Specifier` impl for the `#[bitfield]` struct if the
@@ -47,6 +118,7 @@ impl BitfieldStruct {
         let (impl_generics, ty_generics, where_clause) = self.item_struct.generics.split_for_impl();
         let bits = self.generate_target_or_actual_bitfield_size(config);
         let next_divisible_by_8 = Self::next_divisible_by_8(&bits);
+        let actual_bits = self.generate_bitfield_size();
 
         Some(quote_spanned!(span=>
             const _: () = {
@@ -77,11 +149,12 @@ impl BitfieldStruct {
                     bytes: Self::Bytes,
                 ) -> ::core::result::Result<Self::InOut, ::modular_bitfield::error::InvalidBitPattern<Self::Bytes>>
                 {
-                    // Truncation of BITS is always valid due to maximum of 128
+                    // Bits beyond the sum of all field bits are undefined.
+                    // Truncation of the bits is always valid due to maximum of 128
                     #[allow(clippy::cast_possible_truncation)]
                     let __bf_max_value: Self::Bytes = (1 as Self::Bytes)
-                        .checked_shl(Self::BITS as ::core::primitive::u32)
-                        .unwrap_or(<Self::Bytes>::MAX);
+                        .checked_shl((#actual_bits) as ::core::primitive::u32)
+                        .map_or(<Self::Bytes>::MAX, |__bf_value| __bf_value - 1);
                     if bytes <= __bf_max_value {
                         let __bf_bytes = bytes.to_le_bytes();
                         ::core::result::Result::Ok(Self {
@@ -101,6 +174,68 @@ impl BitfieldStruct {
         let span = self.item_struct.span();
         let ident = &self.item_struct.ident;
         let (impl_generics, ty_generics, where_clause) = self.item_struct.generics.split_for_impl();
+        let fmt_fields = self.generate_fmt_fields(config);
+        Some(quote_spanned!(span=>
+            impl #impl_generics ::core::fmt::Debug for #ident #ty_generics #where_clause {
+                fn fmt(&self, __bf_f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    #fmt_fields
+                }
+            }
+        ))
+    }
+
+    /// Generates the `fields_debug` method returning a wrapper that formats the fields
+    /// like the `#[derive(Debug)]` impl but only decodes them when actually formatted.
+    ///
+    /// The higher-ranked bounds defer the requirement that all field values implement
+    /// `Debug` to the use of `fields_debug`.
+    fn generate_fields_debug(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let (impl_generics, ty_generics, where_clause) = self.item_struct.generics.split_for_impl();
+        let where_predicates = where_clause.map(|w| &w.predicates);
+        let bounds = self
+            .field_infos(config)
+            .filter(|info| !info.config.skip_getters())
+            .map(|info| {
+                let ty = &info.field.ty;
+                quote_spanned!(ty.span()=>
+                    for<'__bf> <#ty as ::modular_bitfield::Specifier>::InOut: ::core::fmt::Debug,
+                )
+            })
+            .collect::<Vec<_>>();
+        let fmt_fields = self.generate_fmt_fields(config);
+        quote_spanned!(span=>
+            impl #impl_generics ::modular_bitfield::private::DebugFields for #ident #ty_generics
+            where
+                #where_predicates
+                #( #bounds )*
+            {
+                fn fmt_fields(&self, __bf_f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    #fmt_fields
+                }
+            }
+
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Returns a wrapper that formats the fields of the bitfield like its
+                /// `#[derive(Debug)]` impl but only decodes them when actually formatted.
+                #[inline]
+                #[must_use]
+                #vis fn fields_debug(&self) -> impl ::core::fmt::Debug + '_
+                where
+                    #( #bounds )*
+                {
+                    ::modular_bitfield::private::FieldsDebug::new(self)
+                }
+            }
+        )
+    }
+
+    /// Generates the body formatting all fields with getters into `__bf_f`.
+    fn generate_fmt_fields(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
         let is_tuple = matches!(self.item_struct.fields, syn::Fields::Unnamed(_));
         let builder_name = if is_tuple {
             quote_spanned!(span=> debug_tuple)
@@ -110,10 +245,11 @@ impl BitfieldStruct {
         let fields = self.field_infos(config).map(|info| {
             let FieldInfo {
                 index: _,
+                accessor: _,
                 field,
-                config,
+                config: field_config,
             } = &info;
-            if config.skip_getters() {
+            if field_config.skip_getters() {
                 return None;
             }
             let field_span = field.span();
@@ -123,11 +259,7 @@ impl BitfieldStruct {
             } else {
                 <_>::default()
             };
-            let field_ident = info.ident_frag();
-            let field_getter = field.ident.as_ref().map_or_else(
-                || format_ident!("get_{}_or_err", field_ident),
-                |_| format_ident!("{}_or_err", field_ident),
-            );
+            let field_getter = Self::checked_getter_ident(config, &info);
             Some(quote_spanned!(field_span=>
                 .field(
                     #field_name
@@ -140,14 +272,26 @@ impl BitfieldStruct {
                 )
             ))
         });
+        quote_spanned!(span=>
+            __bf_f.#builder_name(::core::stringify!(#ident))
+                #( #fields )*
+                .finish()
+        )
+    }
+
+    /// Generates a check emitting a warning if the bitfield has undefined bits
+    /// padding it to full bytes when the `warn_waste` flag is set.
+    fn expand_waste_check(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.warn_waste.as_ref()?.span;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let actual_bits = self.generate_bitfield_size();
         Some(quote_spanned!(span=>
-            impl #impl_generics ::core::fmt::Debug for #ident #ty_generics #where_clause {
-                fn fmt(&self, __bf_f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                    __bf_f.#builder_name(::core::stringify!(#ident))
-                        #( #fields )*
-                        .finish()
-                }
-            }
+            const _: () = {
+                let () = ::modular_bitfield::private::checks::WasteCheck::<{
+                    (#next_divisible_by_8) > (#actual_bits)
+                }>::CHECK;
+            };
         ))
     }
 
@@ -195,17 +339,30 @@ impl BitfieldStruct {
     }
 
     /// Generates the expression denoting the actual configured or implied bit width.
+    ///
+    /// With `pad_to_pow2` this is the bit width of the next power-of-two number of bytes.
     fn generate_target_or_actual_bitfield_size(&self, config: &Config) -> TokenStream2 {
-        config.bits.as_ref().map_or_else(
-            || self.generate_bitfield_size(),
-            |bits_config| {
-                let span = bits_config.span;
-                let value = bits_config.value;
-                quote_spanned!(span=>
-                    #value
-                )
-            },
-        )
+        if let Some(pad_to_pow2) = config.pad_to_pow2.as_ref() {
+            let span = pad_to_pow2.span;
+            let actual_bits = self.generate_bitfield_size();
+            return quote_spanned!(span=>
+                (#actual_bits).div_ceil(8).next_power_of_two() * 8
+            );
+        }
+        config
+            .bits
+            .as_ref()
+            .filter(|bits_config| !matches!(bits_config.value, BitsValue::Range(_)))
+            .map_or_else(
+                || self.generate_bitfield_size(),
+                |bits_config| {
+                    let span = bits_config.span;
+                    let value = &bits_config.value;
+                    quote_spanned!(span=>
+                        #value
+                    )
+                },
+            )
     }
 
     /// Generates a check in case `bits = N` is unset to verify that the actual amount of bits is either
@@ -215,7 +372,7 @@ impl BitfieldStruct {
     fn generate_filled_check_for_unaligned_bits(
         &self,
         config: &Config,
-        required_bits: usize,
+        required_bits: &BitsValue,
     ) -> TokenStream2 {
         let span = self.item_struct.span();
         let ident = &self.item_struct.ident;
@@ -271,15 +428,56 @@ impl BitfieldStruct {
     /// - `filled = false`: Check if the total number of required bits is
     ///   - ... smaller than `N` if `bits = N` was provided or
     ///   - ... NOT a multiple of 8, otherwise
+    ///
+    /// With `bits = A..=B` the total number of required bits must additionally lie within the range.
     fn generate_check_for_filled(&self, config: &Config) -> TokenStream2 {
         match config.bits.as_ref() {
+            Some(ConfigValue {
+                value: BitsValue::Range(range),
+                span,
+            }) => {
+                let range_check = self.generate_bits_range_check(range, *span);
+                let filled_check = self.generate_filled_check_for_aligned_bits(config);
+                quote_spanned!(*span=>
+                    #range_check
+                    #filled_check
+                )
+            }
             Some(bits_config) => {
-                self.generate_filled_check_for_unaligned_bits(config, bits_config.value)
+                self.generate_filled_check_for_unaligned_bits(config, &bits_config.value)
             }
+            // The padding of `pad_to_pow2` may be empty so there is nothing to check.
+            None if config.pad_to_pow2.is_some() => TokenStream2::new(),
             None => self.generate_filled_check_for_aligned_bits(config),
         }
     }
 
+    /// Generates a check in case `bits = A..=B` is set to verify that the actual amount of bits
+    /// lies within the given range.
+    fn generate_bits_range_check(&self, range: &syn::ExprRange, span: Span) -> TokenStream2 {
+        let actual_bits = self.generate_bitfield_size();
+        let lower_check = range
+            .start
+            .as_ref()
+            .map(|start| quote_spanned!(span=> (#start) <= __bf_bits &&));
+        let upper_check = match (&range.end, &range.limits) {
+            (Some(end), syn::RangeLimits::Closed(_)) => quote_spanned!(span=> __bf_bits <= (#end)),
+            (Some(end), syn::RangeLimits::HalfOpen(_)) => quote_spanned!(span=> __bf_bits < (#end)),
+            (None, _) => quote_spanned!(span=> true),
+        };
+        let msg = format!(
+            "the fields of {} do not add up to a bit width within `bits = {}`",
+            self.item_struct.ident,
+            range.to_token_stream(),
+        );
+        quote_spanned!(span=>
+            const _: () = {
+                let __bf_bits: ::core::primitive::usize = #actual_bits;
+                ::core::assert!(#lower_check #upper_check, #msg);
+            };
+        )
+    }
+
     /// Returns a token stream representing the next greater value divisible by 8.
     fn next_divisible_by_8(value: &TokenStream2) -> TokenStream2 {
         let span = value.span();
@@ -288,10 +486,26 @@ impl BitfieldStruct {
         )
     }
 
+    /// Generates the const expression of the byte array masking all defined bits of the bitfield.
+    ///
+    /// Bits beyond the sum of all field bits are undefined even if they are within the
+    /// `bits = N` parameter.
+    fn generate_defined_bits_mask(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let actual_bits = self.generate_bitfield_size();
+        quote_spanned!(span=>
+            ::modular_bitfield::private::defined_bits_mask::<{ #next_divisible_by_8 / 8 }>(#actual_bits)
+        )
+    }
+
     /// Generates the actual item struct definition for the `#[bitfield]`.
     ///
     /// Internally it only contains a byte array equal to the minimum required
     /// amount of bytes to compactly store the information of all its bit fields.
+    /// Unless other representations are requested the struct is `#[repr(transparent)]`
+    /// over this byte array.
     fn generate_struct(&self, config: &Config) -> TokenStream2 {
         let span = self.item_struct.span();
         let attrs = &config.retained_attributes;
@@ -300,8 +514,33 @@ impl BitfieldStruct {
         let generics = &self.item_struct.generics;
         let size = self.generate_target_or_actual_bitfield_size(config);
         let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let layout_docs = self.generate_layout_table(config).map(|table| {
+            quote_spanned!(span=>
+                #[doc = ""]
+                #[doc = #table]
+            )
+        });
+        // Other representations such as `#[repr(align(N))]` cannot be combined with
+        // `#[repr(transparent)]` so the layout is left to the user in that case.
+        let has_repr = attrs.iter().any(|attr| is_repr_meta(&attr.meta));
+        let repr_transparent = (!has_repr).then(|| quote_spanned!(span=> #[repr(transparent)]));
+        let derive_copy = config.copy.as_ref().map(|copy| {
+            quote_spanned!(copy.span=>
+                #[derive(::core::clone::Clone, ::core::marker::Copy)]
+            )
+        });
+        // The bytes of non-filled bitfields are compared by `expand_eq_bytes_impl` instead.
+        let derive_partial_eq = config
+            .derive_partial_eq
+            .as_ref()
+            .filter(|_| config.filled_enabled())
+            .map(|derive| quote_spanned!(derive.span=> #[derive(::core::cmp::PartialEq)]));
         quote_spanned!(span=>
             #( #attrs )*
+            #layout_docs
+            #repr_transparent
+            #derive_copy
+            #derive_partial_eq
             #vis struct #ident #generics
             {
                 bytes: [::core::primitive::u8; #next_divisible_by_8 / 8],
@@ -309,24 +548,205 @@ impl BitfieldStruct {
         )
     }
 
-    /// Generates the constructor for the bitfield that initializes all bytes to zero.
+    /// Generates the markdown table describing the bit layout of the fields if the
+    /// `doc_layout` flag is set.
+    ///
+    /// Bit widths that are not known to the macro, e.g. of enum fields without
+    /// `#[bits = N]`, are described via the `Specifier::BITS` of their type.
+    fn generate_layout_table(&self, config: &Config) -> Option<String> {
+        config.doc_layout.as_ref()?;
+        let mut table = String::from(
+            "# Layout\n\n\
+             | Field | Offset | Width | Type |\n\
+             |-------|--------|-------|------|\n",
+        );
+        let mut rows = Vec::new();
+        let mut known_offset = 0;
+        let mut unknown_offsets = Vec::new();
+        for info in self.field_infos(config) {
+            let ty = info.field.ty.to_token_stream().to_string().replace(' ', "");
+            let unknown_width = format!("`<{ty} as Specifier>::BITS`");
+            let offset = match &info.config.at {
+                Some(at) => at.value.to_string(),
+                None if unknown_offsets.is_empty() => known_offset.to_string(),
+                None if known_offset == 0 => unknown_offsets.join(" + "),
+                None => format!("{known_offset} + {}", unknown_offsets.join(" + ")),
+            };
+            if let Some(at) = &info.config.at {
+                known_offset = at.value;
+                unknown_offsets.clear();
+            }
+            let width = info
+                .config
+                .bits
+                .as_ref()
+                .map(|bits| bits.value)
+                .or_else(|| builtin_specifier_bits(&info.field.ty))
+                .or_else(|| builtin_primitive_bits(&info.field.ty));
+            let width = if let Some(width) = width {
+                known_offset += width;
+                width.to_string()
+            } else {
+                unknown_offsets.push(unknown_width.clone());
+                unknown_width
+            };
+            let name = info.name();
+            rows.push(format!("| `{name}` | {offset} | {width} | `{ty}` |\n"));
+        }
+        table.push_str(&rows.concat());
+        Some(table)
+    }
+
+    /// Generates the `LAYOUT` constant holding the bit layout table if the `doc_layout`
+    /// flag is set.
+    fn expand_layout_const(&self, config: &Config) -> Option<TokenStream2> {
+        let table = self.generate_layout_table(config)?;
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let (impl_generics, ty_generics, where_clause) = self.item_struct.generics.split_for_impl();
+        Some(quote_spanned!(span=>
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// The markdown table describing the bit layout of the fields as shown
+                /// in the documentation of the struct.
+                #vis const LAYOUT: &'static ::core::primitive::str = #table;
+            }
+        ))
+    }
+
+    /// Generates the hidden `__BF_FIELD_LAYOUT` constant holding the bit range of every
+    /// field for the `field_layout` parameter, which backs the `assert_field_layout!` macro.
+    ///
+    /// The ranges are stored in a hidden struct that mirrors the field names of the
+    /// bitfield struct so that the macro can address them by field name.
+    fn expand_field_layout(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.field_layout.as_ref()?.span;
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let (impl_generics, ty_generics, where_clause) = self.item_struct.generics.split_for_impl();
+        let layout_ident = format_ident!("__{}BitfieldLayout", ident.unraw());
+        let is_unique = |ident: &syn::Ident| {
+            self.item_struct
+                .fields
+                .iter()
+                .filter(|field| field.ident.as_ref() == Some(ident))
+                .count()
+                == 1
+        };
+        let mut offset = Punctuated::<syn::Expr, Token![+]>::new();
+        let (fields, ranges): (Vec<_>, Vec<_>) = self
+            .field_infos(config)
+            .filter_map(|info| {
+                let field_span = info.field.span();
+                let ty = &info.field.ty;
+                let start = Self::field_offset(&info, &offset);
+                offset.push(
+                    syn::parse_quote_spanned!(field_span=> <#ty as ::modular_bitfield::Specifier>::BITS),
+                );
+                // Wildcard fields like `__` may occur multiple times and cannot be addressed.
+                if info.field.ident.as_ref().is_some_and(|ident| !is_unique(ident)) {
+                    return None;
+                }
+                let range = quote_spanned!(field_span=>
+                    (#start)..(#start) + <#ty as ::modular_bitfield::Specifier>::BITS
+                );
+                Some(match &info.field.ident {
+                    Some(field_ident) => (
+                        quote_spanned!(field_span=> pub #field_ident: ::core::ops::Range<::core::primitive::usize>),
+                        quote_spanned!(field_span=> #field_ident: #range),
+                    ),
+                    None => (
+                        quote_spanned!(field_span=> pub ::core::ops::Range<::core::primitive::usize>),
+                        range,
+                    ),
+                })
+            })
+            .unzip();
+        let (layout_struct, layout_value) = match &self.item_struct.fields {
+            syn::Fields::Unnamed(_) => (
+                quote_spanned!(span=> #vis struct #layout_ident( #( #fields ),* );),
+                quote_spanned!(span=> #layout_ident( #( #ranges ),* )),
+            ),
+            _ => (
+                quote_spanned!(span=> #vis struct #layout_ident { #( #fields ),* }),
+                quote_spanned!(span=> #layout_ident { #( #ranges ),* }),
+            ),
+        };
+        Some(quote_spanned!(span=>
+            #[doc(hidden)]
+            #[allow(dead_code, non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
+            #layout_struct
+
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #[doc(hidden)]
+                #[allow(dead_code, clippy::identity_op)]
+                #vis const __BF_FIELD_LAYOUT: #layout_ident = #layout_value;
+            }
+        ))
+    }
+
+    /// Generates the constructor for the bitfield that initializes all bytes to zero
+    /// as well as the `ZERO` and `ONES` constants.
     fn generate_constructor(&self, config: &Config) -> TokenStream2 {
         let span = self.item_struct.span();
         let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
         let (impl_generics, ty_generics, where_clause) = self.item_struct.generics.split_for_impl();
         let size = self.generate_target_or_actual_bitfield_size(config);
         let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let defined_bits_mask = self.generate_defined_bits_mask(config);
         quote_spanned!(span=>
             impl #impl_generics #ident #ty_generics #where_clause
             {
                 /// Returns an instance with zero initialized data.
                 #[allow(clippy::new_without_default)]
                 #[must_use]
-                pub const fn new() -> Self {
+                #vis const fn new() -> Self {
                     Self {
                         bytes: [0_u8; #next_divisible_by_8 / 8],
                     }
                 }
+
+                /// An instance with all bits set to zero, equivalent to [`Self::new`].
+                #vis const ZERO: Self = Self::new();
+
+                /// An instance with all defined bits set to one even if this is an invalid
+                /// bit pattern for some of its fields.
+                #[allow(clippy::identity_op)]
+                #vis const ONES: Self = Self {
+                    bytes: #defined_bits_mask,
+                };
+
+                /// The number of bytes of the underlying byte array.
+                #[allow(clippy::identity_op)]
+                #vis const BYTES: ::core::primitive::usize = #next_divisible_by_8 / 8;
+
+                /// Returns `true` if the bit at index `n` of the underlying bits is set.
+                ///
+                /// # Panics
+                ///
+                /// If `n` is not less than the number of bits of the bitfield.
+                #[inline]
+                #[must_use]
+                #[allow(clippy::identity_op, clippy::trivially_copy_pass_by_ref)]
+                #vis const fn contains_bit(&self, n: ::core::primitive::usize) -> ::core::primitive::bool {
+                    ::core::assert!(n < (#size), "bit index out of bounds");
+                    self.bytes[n / 8] & (1 << (n % 8)) != 0
+                }
+
+                /// Copies the bits of `other` into `self` for every bit that is set in `mask`
+                /// and leaves all other bits of `self` untouched.
+                #[inline]
+                #[allow(clippy::trivially_copy_pass_by_ref)]
+                #vis fn overwrite_from(&mut self, other: &Self, mask: &Self) {
+                    let mut __bf_index = 0;
+                    while __bf_index < Self::BYTES {
+                        let __bf_mask = mask.bytes[__bf_index];
+                        self.bytes[__bf_index] = (self.bytes[__bf_index] & !__bf_mask)
+                            | (other.bytes[__bf_index] & __bf_mask);
+                        __bf_index += 1;
+                    }
+                }
             }
         )
     }
@@ -402,17 +822,19 @@ impl BitfieldStruct {
     fn expand_byte_conversion_impls(&self, config: &Config) -> TokenStream2 {
         let span = self.item_struct.span();
         let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
         let (impl_generics, ty_generics, where_clause) = self.item_struct.generics.split_for_impl();
         let size = self.generate_target_or_actual_bitfield_size(config);
         let next_divisible_by_8 = Self::next_divisible_by_8(&size);
         let bytes_ty = quote_spanned!(span=> [::core::primitive::u8; #next_divisible_by_8 / 8]);
+        let defined_bits_mask = self.generate_defined_bits_mask(config);
         let (from_bytes, from_impl) = if config.filled_enabled() {
             (
                 quote_spanned!(span=>
                     /// Converts the given bytes directly into the bitfield struct.
                     #[inline]
                     #[must_use]
-                    pub const fn from_bytes(bytes: #bytes_ty) -> Self {
+                    #vis const fn from_bytes(bytes: #bytes_ty) -> Self {
                         Self { bytes }
                     }
                 ),
@@ -433,16 +855,36 @@ impl BitfieldStruct {
                     ///
                     /// If the given bytes contain bits at positions that are undefined for `Self`.
                     #[inline]
-                    pub fn from_bytes(
+                    #[allow(clippy::identity_op)]
+                    #vis fn from_bytes(
                         bytes: #bytes_ty
                     ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
-                        #[allow(clippy::identity_op)]
-                        if ::core::primitive::u16::from(bytes[(#next_divisible_by_8 / 8) - 1]) < (1 << (8 - (#next_divisible_by_8 - (#size)))) {
+                        let __bf_mask: #bytes_ty = #defined_bits_mask;
+                        if bytes.iter().zip(__bf_mask).all(|(__bf_byte, __bf_mask)| __bf_byte & !__bf_mask == 0) {
                             ::core::result::Result::Ok(Self { bytes })
                         } else {
                             ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
                         }
                     }
+
+                    /// Converts the given bytes into the bitfield struct while clearing all
+                    /// bits at positions that are undefined for `Self`.
+                    ///
+                    /// Also returns `true` if any of the cleared bits was set in `bytes`.
+                    #[inline]
+                    #[must_use]
+                    #[allow(clippy::identity_op)]
+                    #vis fn from_bytes_reporting(
+                        mut bytes: #bytes_ty
+                    ) -> (Self, ::core::primitive::bool) {
+                        let __bf_mask: #bytes_ty = #defined_bits_mask;
+                        let mut __bf_had_undefined = false;
+                        for (__bf_byte, __bf_mask) in bytes.iter_mut().zip(__bf_mask) {
+                            __bf_had_undefined |= *__bf_byte & !__bf_mask != 0;
+                            *__bf_byte &= __bf_mask;
+                        }
+                        (Self { bytes }, __bf_had_undefined)
+                    }
                 ),
                 quote_spanned!(span=>
                     impl #impl_generics ::core::convert::TryFrom<#bytes_ty> for #ident #ty_generics #where_clause {
@@ -474,235 +916,1940 @@ impl BitfieldStruct {
                 /// The returned byte array is layed out in the same way as described
                 /// [here](https://docs.rs/modular-bitfield/#generated-structure).
                 #[inline]
-                pub const fn into_bytes(self) -> #bytes_ty {
+                #vis const fn into_bytes(self) -> #bytes_ty {
                     self.bytes
                 }
 
+                /// Returns a wrapper that formats the underlying bits as a list of
+                /// hexadecimal bytes, e.g. `[0x12, 0xAB]`, using `Debug`.
+                #[inline]
+                #[must_use]
+                #vis fn debug_bytes(&self) -> impl ::core::fmt::Debug + '_ {
+                    ::modular_bitfield::private::DebugBits::new(&self.bytes[..])
+                }
+
                 #from_bytes
             }
         )
     }
 
-    /// Generates code to check for the bit size arguments of bitfields.
-    fn expand_bits_checks_for_field(field_info: FieldInfo<'_>) -> TokenStream2 {
-        let FieldInfo {
-            index: _,
-            field,
-            config,
-        } = field_info;
-        config.bits.as_ref().map(|bits| {
-            let ty = &field.ty;
-            let expected_bits = bits.value;
-            let expected_span = bits.span;
-            let actual_span = field.ty.span();
-            let actual_ty = quote_spanned!(actual_span=>
-                ::modular_bitfield::private::checks::BitCount<{<#ty as ::modular_bitfield::Specifier>::BITS}>
-            );
-            quote_spanned!(expected_span=>
-                let _: #actual_ty = ::modular_bitfield::private::checks::BitCount::<{#expected_bits}>;
-            )
-        }).unwrap_or_default()
+    /// Generates `parse_prefix` decoding the bitfield from the start of a byte slice and
+    /// returning the number of consumed bytes, e.g. for framing in stream parsers.
+    fn expand_parse_prefix(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let (impl_generics, ty_generics, where_clause) = self.item_struct.generics.split_for_impl();
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let from_prefix = if config.filled_enabled() {
+            quote_spanned!(span=> ::core::result::Result::Ok(Self::from_bytes(__bf_bytes)))
+        } else {
+            quote_spanned!(span=> Self::from_bytes(__bf_bytes))
+        };
+        quote_spanned!(span=>
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Decodes the bitfield from the first [`Self::BYTES`] bytes of the given slice
+                /// and returns it together with the number of consumed bytes.
+                ///
+                /// # Errors
+                ///
+                /// If the slice is shorter than [`Self::BYTES`] or its prefix contains bits at
+                /// positions that are undefined for `Self`.
+                #[inline]
+                #vis fn parse_prefix(
+                    bytes: &[::core::primitive::u8],
+                ) -> ::core::result::Result<(Self, ::core::primitive::usize), ::modular_bitfield::error::OutOfBounds> {
+                    let __bf_bytes = bytes
+                        .get(..Self::BYTES)
+                        .and_then(|__bf_prefix| <[::core::primitive::u8; #next_divisible_by_8 / 8] as ::core::convert::TryFrom<&[::core::primitive::u8]>>::try_from(__bf_prefix).ok())
+                        .ok_or(::modular_bitfield::error::OutOfBounds)?;
+                    let __bf_this: ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> = #from_prefix;
+                    __bf_this.map(|__bf_this| (__bf_this, Self::BYTES))
+                }
+            }
+        )
     }
 
-    fn expand_getters_for_field(
-        &self,
-        offset: &TokenStream2,
-        info: &FieldInfo<'_>,
-    ) -> Option<TokenStream2> {
-        let FieldInfo {
-            index: _,
-            field,
-            config,
-        } = info;
-        if config.skip_getters() {
+    /// Generates `read_from` and `write_to` for reading and writing the bitfield through
+    /// `std::io` if the `std` crate feature is enabled.
+    fn expand_io_methods(&self, config: &Config) -> Option<TokenStream2> {
+        if !cfg!(feature = "std") {
             return None;
         }
-        let struct_ident = &self.item_struct.ident;
-        let span = field.span();
-        let ident = info.ident_frag();
-        let name = info.name();
-
-        let retained_attrs = &config.retained_attrs;
-        let get_ident = field
-            .ident
-            .clone()
-            .unwrap_or_else(|| format_ident!("get_{}", ident));
-        let get_checked_ident = field.ident.as_ref().map_or_else(
-            || format_ident!("get_{}_or_err", ident),
-            |_| format_ident!("{}_or_err", ident),
-        );
-        let ty = &field.ty;
-        let vis = &field.vis;
-        let get_assert_msg =
-            format!("value contains invalid bit pattern for field {struct_ident}.{name}");
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let (impl_generics, ty_generics, where_clause) = self.item_struct.generics.split_for_impl();
+        let from_bytes = if config.filled_enabled() {
+            quote_spanned!(span=>
+                ::core::result::Result::Ok(Self::from_bytes(__bf_bytes))
+            )
+        } else {
+            quote_spanned!(span=>
+                Self::from_bytes(__bf_bytes).map_err(::modular_bitfield::private::io::invalid_data)
+            )
+        };
+        Some(quote_spanned!(span=>
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Reads exactly [`Self::BYTES`] bytes from the reader and converts them into
+                /// the bitfield struct.
+                ///
+                /// # Errors
+                ///
+                /// If reading from the reader fails or if the bytes contain bits at positions
+                /// that are undefined for `Self` in which case the error is of kind
+                /// `InvalidData`.
+                #[inline]
+                #vis fn read_from(
+                    reader: &mut impl ::modular_bitfield::private::io::Read,
+                ) -> ::modular_bitfield::private::io::Result<Self> {
+                    let __bf_bytes = ::modular_bitfield::private::io::read_bytes(reader)?;
+                    #from_bytes
+                }
 
-        let getter_docs = format!("Returns the value of `{name}`.");
-        let checked_getter_docs = format!(
-            "Returns the value of `{name}`.\n\n\
-             # Errors\n\n\
-             If the returned value contains an invalid bit pattern for `{name}`.",
-        );
-        let getters = quote_spanned!(span=>
-            #[doc = #getter_docs]
-            #[inline]
-            #[must_use]
-            #( #retained_attrs )*
-            #vis fn #get_ident(&self) -> <#ty as ::modular_bitfield::Specifier>::InOut {
-                self.#get_checked_ident().expect(#get_assert_msg)
+                /// Writes the underlying bytes to the writer.
+                ///
+                /// # Errors
+                ///
+                /// If writing to the writer fails.
+                #[inline]
+                #[allow(clippy::trivially_copy_pass_by_ref)]
+                #vis fn write_to(
+                    &self,
+                    writer: &mut impl ::modular_bitfield::private::io::Write,
+                ) -> ::modular_bitfield::private::io::Result<()> {
+                    writer.write_all(&self.bytes[..])
+                }
             }
+        ))
+    }
 
-            #[doc = #checked_getter_docs]
-            #[inline]
-            #[allow(dead_code)]
-            #( #retained_attrs )*
-            #vis fn #get_checked_ident(
-                &self,
-            ) -> ::core::result::Result<
-                <#ty as ::modular_bitfield::Specifier>::InOut,
-                ::modular_bitfield::error::InvalidBitPattern<<#ty as ::modular_bitfield::Specifier>::Bytes>
+    /// Generates methods to load and store a `#[repr(uN)]` bitfield through the matching
+    /// `core::sync::atomic::AtomicUN` if the `atomic` crate feature is enabled.
+    ///
+    /// There is no stable `AtomicU128` so `#[repr(u128)]` bitfields get none.
+    fn expand_atomic_methods(&self, config: &Config) -> Option<TokenStream2> {
+        if !cfg!(feature = "atomic") {
+            return None;
+        }
+        let repr = config.repr.as_ref()?;
+        let span = repr.span;
+        let (atomic, width) = match repr.value {
+            ReprKind::U8 => (quote_spanned!(span=> AtomicU8), "8"),
+            ReprKind::U16 => (quote_spanned!(span=> AtomicU16), "16"),
+            ReprKind::U32 => (quote_spanned!(span=> AtomicU32), "32"),
+            ReprKind::U64 => (quote_spanned!(span=> AtomicU64), "64"),
+            ReprKind::U128 => return None,
+        };
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let (impl_generics, ty_generics, where_clause) = self.item_struct.generics.split_for_impl();
+        Some(quote_spanned!(span=>
+            #[cfg(target_has_atomic = #width)]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Returns a new atomic integer holding the underlying bits.
+                #[inline]
+                #[must_use]
+                #[allow(clippy::wrong_self_convention)]
+                #vis fn to_atomic(self) -> ::core::sync::atomic::#atomic {
+                    ::core::sync::atomic::#atomic::new(::core::convert::From::from(self))
+                }
+
+                /// Atomically loads the bitfield from the given atomic integer.
+                ///
+                /// `order` must be valid for [`load`](::core::sync::atomic::#atomic::load),
+                /// i.e. neither `Release` nor `AcqRel`. Use `Acquire` to synchronize with a
+                /// `Release` store of another thread.
+                ///
+                /// # Panics
+                ///
+                /// If `order` is `Release` or `AcqRel`.
+                #[inline]
+                #vis fn load_atomic(
+                    atomic: &::core::sync::atomic::#atomic,
+                    order: ::core::sync::atomic::Ordering,
+                ) -> Self {
+                    ::core::convert::From::from(atomic.load(order))
+                }
+
+                /// Atomically stores the bitfield into the given atomic integer.
+                ///
+                /// `order` must be valid for [`store`](::core::sync::atomic::#atomic::store),
+                /// i.e. neither `Acquire` nor `AcqRel`. Use `Release` to publish the value to
+                /// an `Acquire` load of another thread.
+                ///
+                /// # Panics
+                ///
+                /// If `order` is `Acquire` or `AcqRel`.
+                #[inline]
+                #vis fn store_atomic(
+                    self,
+                    atomic: &::core::sync::atomic::#atomic,
+                    order: ::core::sync::atomic::Ordering,
+                ) {
+                    atomic.store(::core::convert::From::from(self), order);
+                }
+            }
+        ))
+    }
+
+    /// Generates the `AsRef<[u8]>` impl and, for filled bitfields, the `AsMut<[u8]>` impl
+    /// exposing the underlying bytes.
+    fn expand_as_bytes_impls(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let (impl_generics, ty_generics, where_clause) = self.item_struct.generics.split_for_impl();
+        // Only filled bitfields have no undefined bits that could be set through `as_mut`.
+        let as_mut_impl = config.filled_enabled().then(|| {
+            quote_spanned!(span=>
+                impl #impl_generics ::core::convert::AsMut<[::core::primitive::u8]> for #ident #ty_generics #where_clause {
+                    #[inline]
+                    fn as_mut(&mut self) -> &mut [::core::primitive::u8] {
+                        &mut self.bytes[..]
+                    }
+                }
+            )
+        });
+        quote_spanned!(span=>
+            impl #impl_generics ::core::convert::AsRef<[::core::primitive::u8]> for #ident #ty_generics #where_clause {
+                #[inline]
+                fn as_ref(&self) -> &[::core::primitive::u8] {
+                    &self.bytes[..]
+                }
+            }
+
+            #as_mut_impl
+        )
+    }
+
+    /// Generates the `FromIterator<bool>` impl assembling the bitfield LSB-first from a
+    /// stream of bits.
+    ///
+    /// Only the bits of the fields may be set so that undefined bits remain zero.
+    fn expand_from_iter_impl(&self) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let (impl_generics, ty_generics, where_clause) = self.item_struct.generics.split_for_impl();
+        let actual_bits = self.generate_bitfield_size();
+        let msg = format!("iterator yields more bits than the fields of {ident} can hold");
+        quote_spanned!(span=>
+            impl #impl_generics ::core::iter::FromIterator<::core::primitive::bool> for #ident #ty_generics #where_clause {
+                #[inline]
+                fn from_iter<__BfI>(iter: __BfI) -> Self
+                where
+                    __BfI: ::core::iter::IntoIterator<Item = ::core::primitive::bool>,
+                {
+                    let mut __bf_this = Self::new();
+                    ::core::assert!(
+                        ::modular_bitfield::private::write_bits_from_iter(
+                            &mut __bf_this.bytes[..],
+                            #actual_bits,
+                            iter,
+                        ),
+                        #msg,
+                    );
+                    __bf_this
+                }
+            }
+        )
+    }
+
+    /// Generates the `Index<usize>` impl over the underlying bits if the `index` flag is set.
+    ///
+    /// Since `Index` must return a reference the bits are returned as references to
+    /// promoted `true` and `false` constants.
+    fn expand_index_impl(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.index.as_ref()?.span;
+        let ident = &self.item_struct.ident;
+        let (impl_generics, ty_generics, where_clause) = self.item_struct.generics.split_for_impl();
+        Some(quote_spanned!(span=>
+            impl #impl_generics ::core::ops::Index<::core::primitive::usize> for #ident #ty_generics #where_clause {
+                type Output = ::core::primitive::bool;
+
+                #[inline]
+                fn index(&self, __bf_index: ::core::primitive::usize) -> &Self::Output {
+                    if self.contains_bit(__bf_index) {
+                        &true
+                    } else {
+                        &false
+                    }
+                }
+            }
+        ))
+    }
+
+    /// Generates the `PartialEq` impl comparing the bitfield against its underlying bytes
+    /// as well as the `const_eq` method comparing two bitfields in const contexts.
+    ///
+    /// A `#[derive(PartialEq)]` of non-filled bitfields is implemented via `const_eq`.
+    ///
+    /// Undefined bits of non-filled bitfields are ignored.
+    fn expand_eq_bytes_impl(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let (impl_generics, ty_generics, where_clause) = self.item_struct.generics.split_for_impl();
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let bytes_ty = quote_spanned!(span=> [::core::primitive::u8; #next_divisible_by_8 / 8]);
+        let defined_bits_mask = self.generate_defined_bits_mask(config);
+        let eq_bytes_body = if config.filled_enabled() {
+            quote_spanned!(span=>
+                self.bytes == *__bf_other
+            )
+        } else {
+            quote_spanned!(span=>
+                // Undefined bits are always zero in `self` so they are masked off in `other`.
+                #[allow(clippy::identity_op)]
+                let __bf_mask: #bytes_ty = #defined_bits_mask;
+                let mut __bf_masked = *__bf_other;
+                for (__bf_byte, __bf_mask) in __bf_masked.iter_mut().zip(__bf_mask) {
+                    *__bf_byte &= __bf_mask;
+                }
+                self.bytes == __bf_masked
+            )
+        };
+        let partial_eq = config
+            .derive_partial_eq
+            .as_ref()
+            .filter(|_| !config.filled_enabled())
+            .map(|derive| {
+                quote_spanned!(derive.span=>
+                    impl #impl_generics ::core::cmp::PartialEq for #ident #ty_generics #where_clause {
+                        #[inline]
+                        fn eq(&self, __bf_other: &Self) -> ::core::primitive::bool {
+                            self.const_eq(__bf_other)
+                        }
+                    }
+                )
+            });
+        quote_spanned!(span=>
+            #partial_eq
+
+            impl #impl_generics ::core::cmp::PartialEq<#bytes_ty> for #ident #ty_generics #where_clause {
+                #[inline]
+                fn eq(&self, __bf_other: &#bytes_ty) -> ::core::primitive::bool {
+                    #eq_bytes_body
+                }
+            }
+
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Returns `true` if all defined bits of `self` and `other` are equal.
+                ///
+                /// Unlike `PartialEq` this can be used in const contexts.
+                #[inline]
+                #[must_use]
+                #[allow(clippy::identity_op, clippy::trivially_copy_pass_by_ref)]
+                #vis const fn const_eq(&self, other: &Self) -> ::core::primitive::bool {
+                    let __bf_mask: #bytes_ty = #defined_bits_mask;
+                    let mut __bf_index = 0;
+                    while __bf_index < __bf_mask.len() {
+                        if (self.bytes[__bf_index] ^ other.bytes[__bf_index]) & __bf_mask[__bf_index] != 0 {
+                            return false;
+                        }
+                        __bf_index += 1;
+                    }
+                    true
+                }
+            }
+        )
+    }
+
+    /// Generates `into_primitive`, `from_primitive`, `to_key` and `from_key` for conversions between the
+    /// `#[bitfield]` struct and the smallest unsigned integer primitive that can
+    /// hold all of its bits.
+    ///
+    /// # Note
+    ///
+    /// The bit width is only known to the compiler so the methods are always
+    /// generated but are only usable for structs with at most 128 bits. The
+    /// higher-ranked bound prevents the compiler from rejecting the unsatisfied
+    /// bound for larger structs up front.
+    fn expand_primitive_conversions(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let (impl_generics, ty_generics, where_clause) = self.item_struct.generics.split_for_impl();
+        let where_predicates = where_clause.map(|w| &w.predicates);
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        // Bits beyond the sum of all field bits are undefined.
+        let actual_bits = self.generate_bitfield_size();
+        let conversion = quote_spanned!(span=>
+            <::modular_bitfield::private::checks::BitCount<{#next_divisible_by_8}> as ::modular_bitfield::private::ArrayBytesConversion>
+        );
+        let prim = quote_spanned!(span=>
+            <::modular_bitfield::private::checks::BitCount<{if #size > 128 { 128 } else { #size }}> as ::modular_bitfield::private::SpecifierBytes>::Bytes
+        );
+        let bound = quote_spanned!(span=>
+            for<'__bf> ::modular_bitfield::private::checks::BitCount<{#next_divisible_by_8}>:
+                ::modular_bitfield::private::ArrayBytesConversion<
+                    Array = [::core::primitive::u8; #next_divisible_by_8 / 8],
+                    Bytes = #prim,
+                >,
+        );
+        let set_masked = config.set_masked.as_ref().map(|set_masked| {
+            Self::expand_set_masked(set_masked.span, vis, &conversion, &bound, &actual_bits)
+        });
+        quote_spanned!(span=>
+            impl #impl_generics #ident #ty_generics
+            where
+                #where_predicates
+            {
+                /// Returns the underlying bits as the smallest unsigned integer primitive
+                /// that is able to hold all of them.
+                ///
+                /// Only available for bitfields with at most 128 bits.
+                #[inline]
+                #[must_use]
+                #vis fn into_primitive(self) -> #prim
+                where
+                    #bound
+                {
+                    #conversion::array_into_bytes(self.bytes)
+                }
+
+                /// Converts the given unsigned integer primitive into the bitfield struct.
+                ///
+                /// Only available for bitfields with at most 128 bits.
+                ///
+                /// # Errors
+                ///
+                /// If the given value has bits set at positions that are undefined for `Self`.
+                #[inline]
+                #vis fn from_primitive(
+                    value: #prim,
+                ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds>
+                where
+                    #bound
+                {
+                    if value <= (!0 >> (::core::mem::size_of_val(&value) * 8 - (#actual_bits))) {
+                        ::core::result::Result::Ok(Self {
+                            bytes: #conversion::bytes_into_array(value),
+                        })
+                    } else {
+                        ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                    }
+                }
+
+                /// Returns the underlying bits zero-extended to a `u128` key.
+                ///
+                /// Unlike [`Self::into_primitive`] the type of the key does not depend on the
+                /// bit width of the bitfield.
+                ///
+                /// Only available for bitfields with at most 128 bits.
+                #[inline]
+                #[must_use]
+                #[allow(clippy::wrong_self_convention, clippy::useless_conversion)]
+                #vis fn to_key(self) -> ::core::primitive::u128
+                where
+                    #bound
+                {
+                    ::core::convert::From::from(self.into_primitive())
+                }
+
+                /// Converts the given `u128` key back into the bitfield struct.
+                ///
+                /// Only available for bitfields with at most 128 bits.
+                ///
+                /// # Errors
+                ///
+                /// If the given key has bits set at positions that are undefined for `Self`.
+                #[inline]
+                #[allow(clippy::useless_conversion)]
+                #vis fn from_key(
+                    key: ::core::primitive::u128,
+                ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds>
+                where
+                    #bound
+                {
+                    match <#prim as ::core::convert::TryFrom<::core::primitive::u128>>::try_from(key) {
+                        ::core::result::Result::Ok(__bf_value) => Self::from_primitive(__bf_value),
+                        ::core::result::Result::Err(_) => {
+                            ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                        }
+                    }
+                }
+
+                /// Overwrites all bits of the bitfield with the given raw `u128` value,
+                /// e.g. to restore a snapshot taken with [`Self::to_key`].
+                ///
+                /// Only available for bitfields with at most 128 bits.
+                ///
+                /// # Errors
+                ///
+                /// If the given value has bits set at positions that are undefined for `Self`.
+                /// In this case `self` is left unchanged.
+                #[inline]
+                #vis fn try_set_all(
+                    &mut self,
+                    raw: ::core::primitive::u128,
+                ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds>
+                where
+                    #bound
+                {
+                    *self = Self::from_key(raw)?;
+                    ::core::result::Result::Ok(())
+                }
+
+                #set_masked
+            }
+        )
+    }
+
+    /// Generates `set_masked` for bitfields with at most 128 bits if the `set_masked` flag is set.
+    fn expand_set_masked(
+        span: Span,
+        vis: &syn::Visibility,
+        conversion: &TokenStream2,
+        bound: &TokenStream2,
+        actual_bits: &TokenStream2,
+    ) -> TokenStream2 {
+        quote_spanned!(span=>
+            /// Overwrites only the bits set in `mask` with the corresponding bits of `value`
+            /// and leaves all other bits untouched, e.g. for read-modify-write updates of a
+            /// subset of fields.
+            ///
+            /// Bits of `mask` at positions that are undefined for `Self` are ignored.
+            /// Note that this does not validate the written bits against the field types.
+            ///
+            /// Only available for bitfields with at most 128 bits.
+            #[inline]
+            #[allow(clippy::cast_possible_truncation, clippy::useless_conversion)]
+            #vis fn set_masked(&mut self, mask: ::core::primitive::u128, value: ::core::primitive::u128)
+            where
+                #bound
+            {
+                let __bf_defined = (!0_u128)
+                    .checked_shr(::core::primitive::usize::saturating_sub(128, #actual_bits) as ::core::primitive::u32)
+                    .unwrap_or(0);
+                let __bf_mask = mask & __bf_defined;
+                let __bf_key: ::core::primitive::u128 =
+                    ::core::convert::From::from(#conversion::array_into_bytes(self.bytes));
+                let __bf_raw = (__bf_key & !__bf_mask) | (value & __bf_mask);
+                // Never fails since `__bf_raw` only has defined bits set.
+                if let ::core::result::Result::Ok(__bf_value) = Self::from_key(__bf_raw) {
+                    *self = __bf_value;
+                }
+            }
+        )
+    }
+
+    /// Generates `From` impls between a `transparent = true` bitfield struct and the
+    /// interface type of its single field.
+    fn expand_passthrough_conversions(&self, config: &Config) -> Option<TokenStream2> {
+        if !config.transparent_enabled() {
+            return None;
+        }
+        let info = self.field_infos(config).next()?;
+        let span = info.field.span();
+        let ident = &self.item_struct.ident;
+        let (impl_generics, ty_generics, where_clause) = self.item_struct.generics.split_for_impl();
+        let ty = &info.field.ty;
+        let specifier_in_out = quote_spanned!(span=> <#ty as ::modular_bitfield::Specifier>::InOut);
+        // Coherence cannot resolve the interface type of specifiers defined in other crates, so
+        // predefined specifiers are spelled out and checked against their actual interface type
+        // in case the name refers to a user defined type.
+        let (in_out, in_out_check) = match builtin_in_out_type(ty) {
+            Some(prim) => {
+                let check = builtin_in_out_check(ty, &prim);
+                (prim, Some(check))
+            }
+            None => (specifier_in_out, None),
+        };
+        let field_ident = info.ident_frag();
+        // The value may be out of bounds for the field, e.g. `0x100_u16` for a `B8` field.
+        let from_inner = (!info.config.skip_setters()).then(|| {
+            let with_checked_ident = format_ident!("with_{}_checked", field_ident);
+            quote_spanned!(span=>
+                impl #impl_generics ::core::convert::TryFrom<#in_out> for #ident #ty_generics #where_clause {
+                    type Error = ::modular_bitfield::error::OutOfBounds;
+
+                    #[inline]
+                    fn try_from(__bf_value: #in_out) -> ::core::result::Result<Self, Self::Error> {
+                        Self::new().#with_checked_ident(__bf_value)
+                    }
+                }
+            )
+        });
+        let into_inner = (!info.config.skip_getters()).then(|| {
+            let get_ident = info
+                .accessor
+                .clone()
+                .unwrap_or_else(|| format_ident!("get_{}", field_ident));
+            quote_spanned!(span=>
+                impl #impl_generics ::core::convert::From<#ident #ty_generics> for #in_out #where_clause {
+                    #[inline]
+                    fn from(__bf_bitfield: #ident #ty_generics) -> Self {
+                        __bf_bitfield.#get_ident()
+                    }
+                }
+            )
+        });
+        Some(quote_spanned!(span=>
+            #in_out_check
+            #from_inner
+            #into_inner
+        ))
+    }
+
+    /// Generates code to check for the bit size arguments of bitfields.
+    fn expand_bits_checks_for_field(field_info: FieldInfo<'_>) -> TokenStream2 {
+        let FieldInfo {
+            index: _,
+            accessor: _,
+            field,
+            config,
+        } = field_info;
+        config.bits.as_ref().map(|bits| {
+            let ty = &field.ty;
+            let expected_bits = bits.value;
+            let expected_span = bits.span;
+            let actual_span = field.ty.span();
+            let actual_ty = quote_spanned!(actual_span=>
+                ::modular_bitfield::private::checks::BitCount<{<#ty as ::modular_bitfield::Specifier>::BITS}>
+            );
+            quote_spanned!(expected_span=>
+                let _: #actual_ty = ::modular_bitfield::private::checks::BitCount::<{#expected_bits}>;
+            )
+        }).unwrap_or_default()
+    }
+
+    fn expand_getters_for_field(
+        &self,
+        config: &Config,
+        offset: &TokenStream2,
+        info: &FieldInfo<'_>,
+    ) -> Option<TokenStream2> {
+        let FieldInfo {
+            index: _,
+            accessor: _,
+            field,
+            config: field_config,
+        } = info;
+        if field_config.skip_getters() {
+            return None;
+        }
+        let struct_ident = &self.item_struct.ident;
+        let span = field.span();
+        let ident = info.ident_frag();
+        let name = info.name();
+
+        let retained_attrs = &field_config.retained_attrs;
+        let get_ident = info
+            .accessor
+            .clone()
+            .unwrap_or_else(|| format_ident!("get_{}", ident));
+        let get_checked_ident = Self::checked_getter_ident(config, info);
+        let ty = &field.ty;
+        let vis = &field.vis;
+        let get_assert_msg =
+            format!("value contains invalid bit pattern for field {struct_ident}.{name}");
+
+        let getter_docs = format!("Returns the value of `{name}`.");
+        let checked_getter_docs = format!(
+            "Returns the value of `{name}`.\n\n\
+             # Errors\n\n\
+             If the returned value contains an invalid bit pattern for `{name}`.",
+        );
+        let ref_getter = Self::expand_ref_getter_for_field(config, offset, info);
+        let read = Self::expand_read_field_bits(info, ty, offset);
+        let as_getter = Self::expand_as_getter_for_field(config, offset, info);
+        let track_caller = Self::expand_track_caller(config, span);
+        let panicking_getter = (!config.fallible_enabled()).then(|| {
+            if let Some(const_getter) =
+                Self::expand_const_getter_for_field(offset, info, &get_ident, &getter_docs)
+            {
+                return const_getter;
+            }
+            quote_spanned!(span=>
+                #[doc = #getter_docs]
+                #[inline]
+                #[must_use]
+                #track_caller
+                #( #retained_attrs )*
+                #vis fn #get_ident(&self) -> <#ty as ::modular_bitfield::Specifier>::InOut {
+                    self.#get_checked_ident().expect(#get_assert_msg)
+                }
+            )
+        });
+        let getters = quote_spanned!(span=>
+            #panicking_getter
+
+            #[doc = #checked_getter_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #get_checked_ident(
+                &self,
+            ) -> ::core::result::Result<
+                <#ty as ::modular_bitfield::Specifier>::InOut,
+                ::modular_bitfield::error::InvalidBitPattern<<#ty as ::modular_bitfield::Specifier>::Bytes>
+            > {
+                let __bf_read: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                    #read
+                };
+                <#ty as ::modular_bitfield::Specifier>::from_bytes(__bf_read)
+            }
+
+
+            #as_getter
+            #ref_getter
+        );
+        Some(getters)
+    }
+
+    /// Generates the getter interpreting the bits of a field as another specifier `T`
+    /// of the same bit width if the `as_getters` flag is set.
+    fn expand_as_getter_for_field(
+        config: &Config,
+        offset: &TokenStream2,
+        info: &FieldInfo<'_>,
+    ) -> Option<TokenStream2> {
+        config.as_getters.as_ref()?;
+        let FieldInfo { field, config, .. } = info;
+        let span = field.span();
+        let ident = info.ident_frag();
+        let name = info.name();
+        let retained_attrs = &config.retained_attrs;
+        let ty = &field.ty;
+        let vis = &field.vis;
+        let get_as_ident = field.ident.as_ref().map_or_else(
+            || format_ident!("get_{}_as", ident),
+            |_| format_ident!("{}_as", ident),
+        );
+        let as_getter_docs = format!(
+            "Returns the bits of `{name}` interpreted as the specifier `T`.\n\n\
+             # Errors\n\n\
+             If the bits of `{name}` contain an invalid bit pattern for `T`.\n\n\
+             # Compile Errors\n\n\
+             If `T` does not have the same bit width as `{name}`.",
+        );
+        let bf_t = quote_spanned!(span=> __BfT);
+        let read_as = Self::expand_read_field_bits(info, &bf_t, offset);
+        let reverse_bound = config.bit_reverse.as_ref().map(|_| {
+            quote_spanned!(span=>
+                <__BfT as ::modular_bitfield::Specifier>::Bytes: ::modular_bitfield::private::ReverseBits,
+            )
+        });
+        Some(quote_spanned!(span=>
+            #[doc = #as_getter_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #get_as_ident<__BfT>(
+                &self,
+            ) -> ::core::result::Result<
+                <__BfT as ::modular_bitfield::Specifier>::InOut,
+                ::modular_bitfield::error::InvalidBitPattern<<__BfT as ::modular_bitfield::Specifier>::Bytes>
+            >
+            where
+                __BfT: ::modular_bitfield::Specifier,
+                ::modular_bitfield::private::PushBuffer<<__BfT as ::modular_bitfield::Specifier>::Bytes>:
+                    ::core::default::Default + ::modular_bitfield::private::PushBits,
+                #reverse_bound
+            {
+                let () = ::modular_bitfield::private::checks::SameBitWidth::<#ty, __BfT>::ASSERT;
+                let __bf_read: <__BfT as ::modular_bitfield::Specifier>::Bytes = {
+                    #read_as
+                };
+                <__BfT as ::modular_bitfield::Specifier>::from_bytes(__bf_read)
+            }
+        ))
+    }
+
+    /// Generates the `const` primary getter of a field with a primitive specifier type.
+    ///
+    /// Primitive fields never hold invalid bit patterns so their getter reads
+    /// the bits directly instead of going through the non-`const` `Specifier::from_bytes`.
+    fn expand_const_getter_for_field(
+        offset: &TokenStream2,
+        info: &FieldInfo<'_>,
+        get_ident: &syn::Ident,
+        getter_docs: &str,
+    ) -> Option<TokenStream2> {
+        if info.config.bit_reverse.is_some() {
+            return None;
+        }
+        let field = info.field;
+        let ty = &field.ty;
+        let span = field.span();
+        // Respanned so that diagnostics about the getter point to the field name only.
+        let prim = builtin_in_out_type(ty)?
+            .into_iter()
+            .map(|mut token| {
+                token.set_span(span);
+                token
+            })
+            .collect::<TokenStream2>();
+        let vis = &field.vis;
+        let retained_attrs = &info.config.retained_attrs;
+        let read = quote_spanned!(span=>
+            ::modular_bitfield::private::read_bits(
+                &self.bytes,
+                #offset,
+                <#ty as ::modular_bitfield::Specifier>::BITS,
+            )
+        );
+        let check = builtin_in_out_check(ty, &prim);
+        let body = if is_bool_type(ty) {
+            quote_spanned!(span=> #read != 0)
+        } else {
+            quote_spanned!(span=> #read as #prim)
+        };
+        Some(quote_spanned!(span=>
+            #[doc = #getter_docs]
+            #[inline]
+            #[must_use]
+            #[allow(clippy::cast_possible_truncation)]
+            #( #retained_attrs )*
+            #vis const fn #get_ident(&self) -> #prim {
+                #check
+                #body
+            }
+        ))
+    }
+
+    /// Generates the getter returning a reference to the underlying bytes of a field if the
+    /// `ref_getters` flag is set.
+    ///
+    /// # Note
+    ///
+    /// Whether a field is byte aligned is only known to the compiler so the getter is
+    /// generated for all fields but only usable for byte aligned fields. It is not generated
+    /// for `#[bit_reverse]` fields whose underlying bytes do not hold the bits in order.
+    fn expand_ref_getter_for_field(
+        config: &Config,
+        offset: &TokenStream2,
+        info: &FieldInfo<'_>,
+    ) -> Option<TokenStream2> {
+        config.ref_getters.as_ref()?;
+        let FieldInfo { field, config, .. } = info;
+        if config.bit_reverse.is_some() {
+            return None;
+        }
+        let span = field.span();
+        let ident = info.ident_frag();
+        let name = info.name();
+        let retained_attrs = &config.retained_attrs;
+        let ty = &field.ty;
+        let vis = &field.vis;
+        let get_ref_ident = field.ident.as_ref().map_or_else(
+            || format_ident!("get_{}_ref", ident),
+            |_| format_ident!("{}_ref", ident),
+        );
+        let ref_getter_docs = format!(
+            "Returns a reference to the underlying bytes of `{name}`.\n\n\
+             Only available if `{name}` starts and ends at byte boundaries.",
+        );
+        Some(quote_spanned!(span=>
+            #[doc = #ref_getter_docs]
+            #[inline]
+            #[allow(dead_code, clippy::eq_op, clippy::erasing_op, clippy::identity_op)]
+            #( #retained_attrs )*
+            #vis fn #get_ref_ident(
+                &self,
+            ) -> &[::core::primitive::u8; <#ty as ::modular_bitfield::Specifier>::BITS / 8]
+            where
+                for<'__bf> <::modular_bitfield::private::checks::BitCount<{
+                    ((#offset) % 8 == 0 && <#ty as ::modular_bitfield::Specifier>::BITS % 8 == 0) as ::core::primitive::usize
+                }> as ::modular_bitfield::private::checks::DispatchTrueFalse>::Out:
+                    ::modular_bitfield::private::checks::FieldIsByteAligned,
+            {
+                let __bf_start = (#offset) / 8;
+                let __bf_end = __bf_start + <#ty as ::modular_bitfield::Specifier>::BITS / 8;
+                ::core::convert::TryFrom::try_from(&self.bytes[__bf_start..__bf_end])
+                    .unwrap_or_else(|_| ::core::unreachable!())
+            }
+        ))
+    }
+
+    fn expand_setters_for_field(
+        &self,
+        config: &Config,
+        offset: &TokenStream2,
+        info: &FieldInfo<'_>,
+    ) -> Option<TokenStream2> {
+        let FieldInfo {
+            index: _,
+            accessor: _,
+            field,
+            config: field_config,
+        } = info;
+        if field_config.skip_setters() {
+            return None;
+        }
+        let span = field.span();
+        let retained_attrs = &field_config.retained_attrs;
+
+        let ident = info.ident_frag();
+        let name = info.name();
+        let ty = &field.ty;
+        let vis = &field.vis;
+
+        let (set_checked_ident, with_checked_ident) = if config.fallible_enabled() {
+            (
+                format_ident!("set_{}", ident),
+                format_ident!("with_{}", ident),
+            )
+        } else {
+            (
+                format_ident!("set_{}_checked", ident),
+                format_ident!("with_{}_checked", ident),
+            )
+        };
+
+        let checked_setter_docs = format!(
+            "Sets the value of `{name}` to the given value.\n\n\
+             # Errors\n\n\
+             If the given value is out of bounds for `{name}`.",
+        );
+        let checked_with_docs = format!(
+            "Returns a copy of the bitfield with the value of `{name}` \
+             set to the given value.\n\n\
+             # Errors\n\n\
+             If the given value is out of bounds for `{name}`.",
+        );
+        let panicking_setters =
+            self.expand_panicking_setters_for_field(config, info, &set_checked_ident);
+        let raw_bits_setter = Self::expand_raw_bits_setter_for_field(config, offset, info);
+        let write = Self::expand_write_field_bits(info, offset, &format_ident!("__bf_raw_val"));
+        let setters = quote_spanned!(span=>
+            #panicking_setters
+
+            #[doc = #checked_with_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #with_checked_ident(
+                mut self,
+                new_val: <#ty as ::modular_bitfield::Specifier>::InOut,
+            ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
+                self.#set_checked_ident(new_val)?;
+                ::core::result::Result::Ok(self)
+            }
+
+            #[doc = #checked_setter_docs]
+            #[inline]
+            #( #retained_attrs )*
+            #vis fn #set_checked_ident(
+                &mut self,
+                new_val: <#ty as ::modular_bitfield::Specifier>::InOut
+            ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
+                const __BF_BASE_BITS: ::core::primitive::usize =
+                    ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::Bytes>() * 8;
+                const __BF_MAX_VALUE: <#ty as ::modular_bitfield::Specifier>::Bytes =
+                    !0 >> (__BF_BASE_BITS - <#ty as ::modular_bitfield::Specifier>::BITS);
+                let __bf_raw_val =
+                    <#ty as ::modular_bitfield::Specifier>::into_bytes(new_val)?;
+                // Value comparison to const guarantees the optimiser eliminates
+                // branching entirely when the maximum value is the same as the
+                // maximum value of the underlying type.
+                #[allow(clippy::absurd_extreme_comparisons)]
+                if __bf_raw_val <= __BF_MAX_VALUE {
+                    #write
+                    ::core::result::Result::Ok(())
+                } else {
+                    ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                }
+            }
+
+            #raw_bits_setter
+        );
+        Some(setters)
+    }
+
+    /// Generates the setter writing the raw bits of a field after checking that they
+    /// decode to a valid value, e.g. a declared enum variant, if the `raw_setters` flag is set.
+    fn expand_raw_bits_setter_for_field(
+        config: &Config,
+        offset: &TokenStream2,
+        info: &FieldInfo<'_>,
+    ) -> Option<TokenStream2> {
+        config.raw_setters.as_ref()?;
+        let field = info.field;
+        let span = field.span();
+        let retained_attrs = &info.config.retained_attrs;
+        let name = info.name();
+        let ty = &field.ty;
+        let vis = &field.vis;
+        let set_bits_ident = format_ident!("set_{}_bits", info.ident_frag());
+        let write = Self::expand_write_field_bits(info, offset, &format_ident!("raw"));
+        let set_bits_docs = format!(
+            "Sets the raw bits of `{name}` after checking that they decode to a valid value.\n\n\
+             # Errors\n\n\
+             If the given bits are out of bounds or an invalid bit pattern for `{name}`.",
+        );
+        Some(quote_spanned!(span=>
+            #[doc = #set_bits_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #set_bits_ident(
+                &mut self,
+                raw: <#ty as ::modular_bitfield::Specifier>::Bytes,
+            ) -> ::core::result::Result<
+                (),
+                ::modular_bitfield::error::InvalidBitPattern<<#ty as ::modular_bitfield::Specifier>::Bytes>
             > {
+                const __BF_BASE_BITS: ::core::primitive::usize =
+                    ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::Bytes>() * 8;
+                const __BF_MAX_VALUE: <#ty as ::modular_bitfield::Specifier>::Bytes =
+                    !0 >> (__BF_BASE_BITS - <#ty as ::modular_bitfield::Specifier>::BITS);
+                #[allow(clippy::absurd_extreme_comparisons)]
+                if raw > __BF_MAX_VALUE {
+                    return ::core::result::Result::Err(::modular_bitfield::error::InvalidBitPattern::new(raw));
+                }
+                <#ty as ::modular_bitfield::Specifier>::from_bytes(raw)?;
+                #write
+                ::core::result::Result::Ok(())
+            }
+        ))
+    }
+
+    /// Returns the identifier of the getter returning a `Result` for the given field.
+    ///
+    /// This is `f_or_err` by default and `f` for `fallible = true` bitfields.
+    fn checked_getter_ident(config: &Config, info: &FieldInfo<'_>) -> syn::Ident {
+        let ident = info.ident_frag();
+        match (&info.accessor, config.fallible_enabled()) {
+            (Some(accessor), true) => accessor.clone(),
+            (Some(_), false) => format_ident!("{}_or_err", ident),
+            (None, true) => format_ident!("get_{}", ident),
+            (None, false) => format_ident!("get_{}_or_err", ident),
+        }
+    }
+
+    /// Generates the `#[track_caller]` attribute for panicking getters and setters
+    /// if the `track_caller` flag is set.
+    fn expand_track_caller(config: &Config, span: Span) -> Option<TokenStream2> {
+        config
+            .track_caller
+            .as_ref()
+            .map(|_| quote_spanned!(span=> #[track_caller]))
+    }
+
+    /// Generates the panicking `set_f` and `with_f` setters of a field.
+    ///
+    /// Nothing is generated for `fallible = true` bitfields.
+    fn expand_panicking_setters_for_field(
+        &self,
+        config: &Config,
+        info: &FieldInfo<'_>,
+        set_checked_ident: &syn::Ident,
+    ) -> Option<TokenStream2> {
+        if config.fallible_enabled() {
+            return None;
+        }
+        let struct_ident = &self.item_struct.ident;
+        let field = info.field;
+        let span = field.span();
+        let retained_attrs = &info.config.retained_attrs;
+        let ident = info.ident_frag();
+        let name = info.name();
+        let ty = &field.ty;
+        let vis = &field.vis;
+        let set_ident = format_ident!("set_{}", ident);
+        let with_ident = format_ident!("with_{}", ident);
+        let set_assert_msg = format!("value out of bounds for field {struct_ident}.{name}");
+        let setter_docs = format!(
+            "Sets the value of `{name}` to the given value.\n\n\
+             # Panics\n\n\
+             If the given value is out of bounds for `{name}`.",
+        );
+        let with_docs = format!(
+            "Returns a copy of the bitfield with the value of `{name}` \
+             set to the given value.\n\n\
+             # Panics\n\n\
+             If the given value is out of bounds for `{name}`.",
+        );
+        let track_caller = Self::expand_track_caller(config, span);
+        Some(quote_spanned!(span=>
+            #[doc = #with_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #[must_use]
+            #track_caller
+            #( #retained_attrs )*
+            #vis fn #with_ident(
+                mut self,
+                new_val: <#ty as ::modular_bitfield::Specifier>::InOut
+            ) -> Self {
+                self.#set_ident(new_val);
+                self
+            }
+
+            #[doc = #setter_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #track_caller
+            #( #retained_attrs )*
+            #vis fn #set_ident(&mut self, new_val: <#ty as ::modular_bitfield::Specifier>::InOut) {
+                self.#set_checked_ident(new_val).expect(#set_assert_msg);
+            }
+        ))
+    }
+
+    /// Generates accessors interpreting the bits of a `BN` field with `N <= 64` as a signed
+    /// two's complement integer.
+    ///
+    /// For field `f` these include `f_signed` and `set_f_signed` as well as
+    /// `set_f_signed_checked` unless `fallible = true` in which case `set_f_signed`
+    /// returns the error instead of panicking.
+    fn expand_signed_accessors_for_field(
+        config: &Config,
+        offset: &TokenStream2,
+        info: &FieldInfo<'_>,
+    ) -> Option<TokenStream2> {
+        let signed = info.config.signed.as_ref()?;
+        let field = info.field;
+        let Some(bits) = builtin_specifier_bits(&field.ty).filter(|bits| *bits <= 64) else {
+            let message =
+                "encountered #[signed] on a field that is not of type `BN` with `N <= 64`";
+            return Some(format_err!(signed.span, "{}", message).to_compile_error());
+        };
+        let shift = 64 - u32::try_from(bits).expect("checked to be at most 64");
+        let span = field.span();
+        let read = Self::expand_read_field_bits(info, &field.ty, offset);
+        let retained_attrs = &info.config.retained_attrs;
+        let ident = info.ident_frag();
+        let name = info.name();
+        let ty = &field.ty;
+        let vis = &field.vis;
+        let getter = (!info.config.skip_getters()).then(|| {
+            let get_ident = match &field.ident {
+                Some(_) => format_ident!("{}_signed", ident),
+                None => format_ident!("get_{}_signed", ident),
+            };
+            let docs = format!(
+                "Returns the bits of `{name}` interpreted as a signed two's complement integer."
+            );
+            quote_spanned!(span=>
+                #[doc = #docs]
+                #[inline]
+                #[must_use]
+                #[allow(dead_code, clippy::cast_possible_wrap, clippy::cast_lossless)]
+                #( #retained_attrs )*
+                #vis fn #get_ident(&self) -> ::core::primitive::i64 {
+                    const __BF_SHIFT: ::core::primitive::u32 = #shift;
+                    let __bf_read: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                        #read
+                    };
+                    ((__bf_read as ::core::primitive::u64) << __BF_SHIFT) as ::core::primitive::i64 >> __BF_SHIFT
+                }
+            )
+        });
+        let setters = (!info.config.skip_setters()).then(|| {
+            let set_checked_ident = if config.fallible_enabled() {
+                format_ident!("set_{}", ident)
+            } else {
+                format_ident!("set_{}_checked", ident)
+            };
+            let set_signed_checked_ident = if config.fallible_enabled() {
+                format_ident!("set_{}_signed", ident)
+            } else {
+                format_ident!("set_{}_signed_checked", ident)
+            };
+            let checked_docs = format!(
+                "Sets the bits of `{name}` to the two's complement of the given signed value.\n\n\
+                 # Errors\n\n\
+                 If the given value is out of bounds for a signed `{bits}` bit integer.",
+            );
+            let panicking_setter = (!config.fallible_enabled()).then(|| {
+                let set_ident = format_ident!("set_{}_signed", ident);
+                let assert_msg = format!("signed value out of bounds for field {name}");
+                let docs = format!(
+                    "Sets the bits of `{name}` to the two's complement of the given signed value.\n\n\
+                     # Panics\n\n\
+                     If the given value is out of bounds for a signed `{bits}` bit integer.",
+                );
+                let track_caller = Self::expand_track_caller(config, span);
+                quote_spanned!(span=>
+                    #[doc = #docs]
+                    #[inline]
+                    #[allow(dead_code)]
+                    #track_caller
+                    #( #retained_attrs )*
+                    #vis fn #set_ident(&mut self, new_val: ::core::primitive::i64) {
+                        self.#set_signed_checked_ident(new_val).expect(#assert_msg);
+                    }
+                )
+            });
+            quote_spanned!(span=>
+                #panicking_setter
+
+                #[doc = #checked_docs]
+                #[inline]
+                #[allow(dead_code, clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+                #( #retained_attrs )*
+                #vis fn #set_signed_checked_ident(
+                    &mut self,
+                    new_val: ::core::primitive::i64,
+                ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
+                    const __BF_SHIFT: ::core::primitive::u32 = #shift;
+                    if !((::core::primitive::i64::MIN >> __BF_SHIFT)..=(::core::primitive::i64::MAX >> __BF_SHIFT)).contains(&new_val) {
+                        return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds);
+                    }
+                    let __bf_raw_val = (new_val as ::core::primitive::u64) & (::core::primitive::u64::MAX >> __BF_SHIFT);
+                    self.#set_checked_ident(__bf_raw_val as <#ty as ::modular_bitfield::Specifier>::InOut)
+                }
+            )
+        });
+        Some(quote_spanned!(span=>
+            #getter
+            #setters
+        ))
+    }
+
+    fn expand_getters_and_setters_for_field(
+        &self,
+        config: &Config,
+        offset: &mut Punctuated<syn::Expr, syn::Token![+]>,
+        info: &FieldInfo<'_>,
+    ) -> TokenStream2 {
+        let field = info.field;
+        let span = field.span();
+        let ty = &field.ty;
+        let offset_ts = Self::field_offset(info, offset);
+        let getters = self.expand_getters_for_field(config, &offset_ts, info);
+        let setters = self.expand_setters_for_field(config, &offset_ts, info);
+        let signed_accessors = Self::expand_signed_accessors_for_field(config, &offset_ts, info);
+        let expect_check = Self::expand_expect_check_for_field(&offset_ts, info);
+        let getters_and_setters = quote_spanned!(span=>
+            #getters
+            #setters
+            #signed_accessors
+            #expect_check
+        );
+        offset.push(syn::parse_quote_spanned!(span=> <#ty as ::modular_bitfield::Specifier>::BITS));
+        getters_and_setters
+    }
+
+    /// Generates `is_valid_f` checking that the field holds the value of its
+    /// `#[expect_value = value]` attribute if any.
+    fn expand_expect_check_for_field(
+        offset: &TokenStream2,
+        info: &FieldInfo<'_>,
+    ) -> Option<TokenStream2> {
+        let expect = info.config.expect_value.as_ref()?;
+        let field = info.field;
+        let ty = &field.ty;
+        let vis = &field.vis;
+        let value = &expect.value;
+        let name = info.name();
+        let is_valid_ident = format_ident!("is_valid_{}", info.ident_frag());
+        let read = Self::expand_read_field_bits(info, ty, offset);
+        let docs = format!(
+            "Returns `true` if `{name}` holds its expected value `{}`.",
+            value.to_token_stream().to_string().replace(' ', ""),
+        );
+        Some(quote_spanned!(expect.span=>
+            #[doc = #docs]
+            #[inline]
+            #[must_use]
+            #[allow(dead_code)]
+            #vis fn #is_valid_ident(&self) -> ::core::primitive::bool {
                 let __bf_read: <#ty as ::modular_bitfield::Specifier>::Bytes = {
-                    ::modular_bitfield::private::read_specifier::<#ty>(&self.bytes[..], #offset)
+                    #read
+                };
+                <#ty as ::modular_bitfield::Specifier>::from_bytes(__bf_read)
+                    .is_ok_and(|__bf_value| __bf_value == #value)
+            }
+        ))
+    }
+
+    /// Generates `is_well_formed` checking all `#[expect_value = value]` fields if there are any.
+    fn expand_is_well_formed(&self, config: &Config) -> Option<TokenStream2> {
+        let checks = self
+            .field_infos(config)
+            .filter(|info| info.config.expect_value.is_some())
+            .map(|info| format_ident!("is_valid_{}", info.ident_frag()))
+            .collect::<Vec<_>>();
+        if checks.is_empty() {
+            return None;
+        }
+        let span = self.item_struct.span();
+        let vis = &self.item_struct.vis;
+        Some(quote_spanned!(span=>
+            /// Returns `true` if all fields with an `#[expect_value = value]` attribute hold
+            /// their expected values.
+            #[inline]
+            #[must_use]
+            #[allow(dead_code)]
+            #vis fn is_well_formed(&self) -> ::core::primitive::bool {
+                #( self.#checks() )&&*
+            }
+        ))
+    }
+
+    /// Returns the bit offset of the field which is either given by `#[at(bit = N)]` or
+    /// the sum of the bit widths of all preceding fields.
+    fn field_offset(
+        info: &FieldInfo<'_>,
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+    ) -> TokenStream2 {
+        let span = info.field.span();
+        if let Some(at) = &info.config.at {
+            let bit = syn::LitInt::new(&at.value.to_string(), at.span);
+            quote_spanned!(at.span=> #bit)
+        } else if offset.is_empty() {
+            quote_spanned!(span=> 0)
+        } else {
+            offset.to_token_stream()
+        }
+    }
+
+    /// Generates the expression reading the raw bits of the field as the specifier `ty`
+    /// whose order is reversed for `#[bit_reverse]` fields.
+    fn expand_read_field_bits(
+        info: &FieldInfo<'_>,
+        ty: &impl ToTokens,
+        offset: &TokenStream2,
+    ) -> TokenStream2 {
+        let span = info.field.span();
+        let read = quote_spanned!(span=>
+            ::modular_bitfield::private::read_specifier::<#ty>(&self.bytes[..], #offset)
+        );
+        match &info.config.bit_reverse {
+            Some(_) => {
+                quote_spanned!(span=> ::modular_bitfield::private::reverse_bits::<#ty>(#read))
+            }
+            None => read,
+        }
+    }
+
+    /// Generates the statement writing the raw bits in `value` to the field whose order
+    /// is reversed for `#[bit_reverse]` fields.
+    fn expand_write_field_bits(
+        info: &FieldInfo<'_>,
+        offset: &TokenStream2,
+        value: &syn::Ident,
+    ) -> TokenStream2 {
+        let span = info.field.span();
+        let ty = &info.field.ty;
+        let value = match &info.config.bit_reverse {
+            Some(_) => {
+                quote_spanned!(span=> ::modular_bitfield::private::reverse_bits::<#ty>(#value))
+            }
+            None => value.to_token_stream(),
+        };
+        quote_spanned!(span=>
+            ::modular_bitfield::private::write_specifier::<#ty>(&mut self.bytes[..], #offset, #value);
+        )
+    }
+
+    /// Generates `checked_into_bytes` which validates the bit pattern of every field
+    /// before returning the underlying bytes.
+    fn expand_checked_into_bytes(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let (impl_generics, ty_generics, where_clause) = self.item_struct.generics.split_for_impl();
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let mut offset = Punctuated::<syn::Expr, Token![+]>::new();
+        let checks = self
+            .field_infos(config)
+            .map(|info| {
+                let field_span = info.field.span();
+                let ty = &info.field.ty;
+                let name = info.name();
+                let field_offset = Self::field_offset(&info, &offset);
+                let read = Self::expand_read_field_bits(&info, ty, &field_offset);
+                offset.push(syn::parse_quote_spanned!(field_span=> <#ty as ::modular_bitfield::Specifier>::BITS));
+                quote_spanned!(field_span=>
+                    let __bf_raw = #read;
+                    if <#ty as ::modular_bitfield::Specifier>::from_bytes(__bf_raw).is_err() {
+                        return ::core::result::Result::Err(::modular_bitfield::error::FieldError::new(#name));
+                    }
+                )
+            })
+            .collect::<Vec<_>>();
+        quote_spanned!(span=>
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Returns the underlying bits after checking that every field holds a valid
+                /// bit pattern for its type.
+                ///
+                /// # Errors
+                ///
+                /// If any field holds an invalid bit pattern, e.g. an undefined enum variant.
+                #[inline]
+                #vis fn checked_into_bytes(
+                    self,
+                ) -> ::core::result::Result<[::core::primitive::u8; #next_divisible_by_8 / 8], ::modular_bitfield::error::FieldError> {
+                    #( #checks )*
+                    ::core::result::Result::Ok(self.bytes)
+                }
+            }
+        )
+    }
+
+    /// Generates `try_get_all` and `get_all` returning the values of all fields with
+    /// getters as a tuple in declaration order.
+    ///
+    /// The panicking `get_all` is not generated for `fallible = true` bitfields.
+    fn expand_get_all(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let (impl_generics, ty_generics, where_clause) = self.item_struct.generics.split_for_impl();
+        let (types, values): (Vec<_>, Vec<_>) = self
+            .field_infos(config)
+            .filter(|info| !info.config.skip_getters())
+            .map(|info| {
+                let field_span = info.field.span();
+                let ty = &info.field.ty;
+                let name = info.name();
+                let get_checked_ident = Self::checked_getter_ident(config, &info);
+                (
+                    quote_spanned!(field_span=> <#ty as ::modular_bitfield::Specifier>::InOut),
+                    quote_spanned!(field_span=>
+                        self.#get_checked_ident()
+                            .map_err(|_| ::modular_bitfield::error::FieldError::new(#name))?
+                    ),
+                )
+            })
+            .unzip();
+        let track_caller = Self::expand_track_caller(config, span);
+        let get_all = (!config.fallible_enabled()).then(|| {
+            quote_spanned!(span=>
+                /// Returns the values of all fields with getters as a tuple in declaration order.
+                ///
+                /// # Panics
+                ///
+                /// If any field holds an invalid bit pattern, e.g. an undefined enum variant.
+                #[inline]
+                #[must_use]
+                #track_caller
+                #vis fn get_all(&self) -> ( #( #types, )* ) {
+                    match self.try_get_all() {
+                        ::core::result::Result::Ok(__bf_values) => __bf_values,
+                        ::core::result::Result::Err(__bf_err) => ::core::panic!("{}", __bf_err),
+                    }
+                }
+            )
+        });
+        quote_spanned!(span=>
+            #[allow(clippy::type_complexity, clippy::trivially_copy_pass_by_ref)]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Returns the values of all fields with getters as a tuple in declaration order.
+                ///
+                /// # Errors
+                ///
+                /// If any field holds an invalid bit pattern, e.g. an undefined enum variant.
+                #[inline]
+                #vis fn try_get_all(
+                    &self,
+                ) -> ::core::result::Result<( #( #types, )* ), ::modular_bitfield::error::FieldError> {
+                    ::core::result::Result::Ok(( #( #values, )* ))
+                }
+
+                #get_all
+            }
+        )
+    }
+
+    /// Generates `from_fields` constructing the bitfield from the values of all fields
+    /// with setters in declaration order.
+    ///
+    /// If all of these fields are of type `bool`, `uN` or `BN` the constructor is a
+    /// panicking `const fn`. Otherwise, or for `fallible = true` bitfields, it returns
+    /// a `Result` instead.
+    fn expand_from_fields(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let (impl_generics, ty_generics, where_clause) = self.item_struct.generics.split_for_impl();
+        let mut offset = Punctuated::<syn::Expr, Token![+]>::new();
+        let mut fields = Vec::new();
+        for info in self.field_infos(config) {
+            let field_span = info.field.span();
+            let ty = &info.field.ty;
+            let field_offset = Self::field_offset(&info, &offset);
+            offset.push(syn::parse_quote_spanned!(field_span=> <#ty as ::modular_bitfield::Specifier>::BITS));
+            if !info.config.skip_setters() {
+                let param = info
+                    .accessor
+                    .clone()
+                    .unwrap_or_else(|| format_ident!("field_{}", info.index));
+                fields.push((info, param, field_offset));
+            }
+        }
+        let params = fields.iter().map(|(info, param, _)| {
+            let ty = &info.field.ty;
+            let in_out = builtin_in_out_type(ty)
+                .filter(|_| !config.fallible_enabled())
+                .unwrap_or_else(
+                    || quote_spanned!(ty.span()=> <#ty as ::modular_bitfield::Specifier>::InOut),
+                );
+            quote_spanned!(param.span()=> #param: #in_out)
+        });
+        let in_out_checks = fields
+            .iter()
+            .filter(|_| !config.fallible_enabled())
+            .filter_map(|(info, _, _)| {
+                let ty = &info.field.ty;
+                builtin_in_out_type(ty).map(|prim| builtin_in_out_check(ty, &prim))
+            })
+            .collect::<Vec<_>>();
+        let is_const = !config.fallible_enabled()
+            && fields.iter().all(|(info, _, _)| {
+                builtin_in_out_type(&info.field.ty).is_some() && info.config.bit_reverse.is_none()
+            });
+        let docs = "Returns an instance with the given values of all fields with setters \
+                    in declaration order.";
+        if is_const {
+            let writes = fields.iter().map(|(info, param, field_offset)| {
+                Self::expand_const_field_write(ident, info, param, field_offset)
+            });
+            quote_spanned!(span=>
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    #[doc = #docs]
+                    ///
+                    /// # Panics
+                    ///
+                    /// If any value is out of bounds for its field.
+                    #[inline]
+                    #[must_use]
+                    #[allow(
+                        clippy::too_many_arguments,
+                        clippy::many_single_char_names,
+                        clippy::fn_params_excessive_bools,
+                        clippy::cast_lossless,
+                        clippy::unnecessary_cast
+                    )]
+                    #vis const fn from_fields( #( #params ),* ) -> Self {
+                        #( #in_out_checks )*
+                        let __bf_bytes = Self::new().bytes;
+                        #( #writes )*
+                        Self { bytes: __bf_bytes }
+                    }
+                }
+            )
+        } else {
+            let set_checked_idents = fields.iter().map(|(info, _, _)| {
+                if config.fallible_enabled() {
+                    format_ident!("set_{}", info.ident_frag())
+                } else {
+                    format_ident!("set_{}_checked", info.ident_frag())
+                }
+            });
+            let args = fields.iter().map(|(_, param, _)| param);
+            quote_spanned!(span=>
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    #[doc = #docs]
+                    ///
+                    /// # Errors
+                    ///
+                    /// If any value is out of bounds for its field.
+                    #[inline]
+                    #[allow(
+                        clippy::too_many_arguments,
+                        clippy::many_single_char_names,
+                        clippy::fn_params_excessive_bools
+                    )]
+                    #vis fn from_fields(
+                        #( #params ),*
+                    ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
+                        #( #in_out_checks )*
+                        let mut __bf_this = Self::new();
+                        #( __bf_this.#set_checked_idents(#args)?; )*
+                        ::core::result::Result::Ok(__bf_this)
+                    }
+                }
+            )
+        }
+    }
+
+    /// Generates the bounds check and `const` write of a single primitive field value in
+    /// the `const` variant of `from_fields`.
+    fn expand_const_field_write(
+        struct_ident: &syn::Ident,
+        info: &FieldInfo<'_>,
+        param: &syn::Ident,
+        field_offset: &TokenStream2,
+    ) -> TokenStream2 {
+        let ty = &info.field.ty;
+        let msg = format!(
+            "value out of bounds for field {struct_ident}.{}",
+            info.name()
+        );
+        quote_spanned!(param.span()=>
+            ::core::assert!(
+                ::modular_bitfield::private::fits_bits(
+                    #param as ::core::primitive::u128,
+                    <#ty as ::modular_bitfield::Specifier>::BITS,
+                ),
+                #msg,
+            );
+            let __bf_bytes = ::modular_bitfield::private::write_bits(
+                __bf_bytes,
+                #field_offset,
+                <#ty as ::modular_bitfield::Specifier>::BITS,
+                #param as ::core::primitive::u128,
+            );
+        )
+    }
+
+    /// Generates `check_round_trip` for test builds if the `prop_test` flag is set.
+    ///
+    /// For every field with getters and setters a few deterministic raw values, namely
+    /// zero, one, all ones and alternating bit patterns, are written on top of an all
+    /// zeros and an all ones bitfield. Raw values that are no valid bit pattern for the
+    /// field are skipped. Each write must be read back as is and must not affect the bits
+    /// of any other field. Finally the bytes of the bitfield must survive `from_bytes`.
+    fn expand_round_trip_check(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.prop_test.as_ref()?.span;
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let (impl_generics, ty_generics, where_clause) = self.item_struct.generics.split_for_impl();
+        let checks = self
+            .field_infos(config)
+            .filter(|info| !info.config.skip_getters() && !info.config.skip_setters())
+            .map(|info| {
+                let field_span = info.field.span();
+                let ty = &info.field.ty;
+                let frag = info.ident_frag();
+                let set_checked_ident = if config.fallible_enabled() {
+                    format_ident!("set_{}", frag)
+                } else {
+                    format_ident!("set_{}_checked", frag)
                 };
-                <#ty as ::modular_bitfield::Specifier>::from_bytes(__bf_read)
+                let get_checked_ident = Self::checked_getter_ident(config, &info);
+                let set_msg = format!("failed to set `{}` to a valid bit pattern", info.name());
+                let get_msg = format!("failed to read back `{}`", info.name());
+                let value_msg = format!("`{}` did not round trip", info.name());
+                let others_msg = format!("setting `{}` changed other bits", info.name());
+                quote_spanned!(field_span=>
+                    for __bf_base in [Self::ZERO, Self::ONES] {
+                        for __bf_raw in __bf_patterns(<#ty as ::modular_bitfield::Specifier>::BITS) {
+                            let ::core::result::Result::Ok(__bf_raw) =
+                                <<#ty as ::modular_bitfield::Specifier>::Bytes as ::core::convert::TryFrom<::core::primitive::u128>>::try_from(__bf_raw)
+                            else {
+                                continue;
+                            };
+                            let ::core::result::Result::Ok(__bf_value) =
+                                <#ty as ::modular_bitfield::Specifier>::from_bytes(__bf_raw)
+                            else {
+                                continue;
+                            };
+                            let mut __bf_this = Self { bytes: __bf_base.bytes };
+                            __bf_this.#set_checked_ident(__bf_value).expect(#set_msg);
+                            let __bf_read = <#ty as ::modular_bitfield::Specifier>::into_bytes(
+                                __bf_this.#get_checked_ident().ok().expect(#get_msg),
+                            );
+                            ::core::assert!(__bf_read == ::core::result::Result::Ok(__bf_raw), #value_msg);
+                            // All other bits keep the value of the base.
+                            let __bf_expected = if __bf_base.bytes == Self::ZERO.bytes {
+                                __bf_raw.count_ones()
+                            } else {
+                                __bf_ones(&Self::ONES.bytes)
+                                    - <#ty as ::modular_bitfield::Specifier>::BITS as ::core::primitive::u32
+                                    + __bf_raw.count_ones()
+                            };
+                            ::core::assert_eq!(__bf_ones(&__bf_this.bytes), __bf_expected, #others_msg);
+                        }
+                    }
+                )
+            });
+        let from_bytes = if config.filled_enabled() {
+            quote_spanned!(span=> Self::from_bytes(__bf_this.bytes))
+        } else {
+            quote_spanned!(span=>
+                Self::from_bytes(__bf_this.bytes).ok().expect("defined bits were rejected by `from_bytes`")
+            )
+        };
+        Some(quote_spanned!(span=>
+            #[cfg(test)]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Checks that all fields round trip a few deterministic values without
+                /// affecting other fields and that the bytes round trip `from_bytes`.
+                ///
+                /// Only available in test builds.
+                ///
+                /// # Panics
+                ///
+                /// If any of the checks fails.
+                #[allow(
+                    dead_code,
+                    clippy::cast_possible_truncation,
+                    clippy::too_many_lines,
+                    clippy::items_after_statements,
+                )]
+                #vis fn check_round_trip() {
+                    fn __bf_patterns(__bf_bits: ::core::primitive::usize) -> [::core::primitive::u128; 5] {
+                        let __bf_max = (!0_u128)
+                            .checked_shr((128 - __bf_bits) as ::core::primitive::u32)
+                            .unwrap_or(0);
+                        [
+                            0,
+                            1,
+                            __bf_max,
+                            0x5555_5555_5555_5555_5555_5555_5555_5555 & __bf_max,
+                            0xAAAA_AAAA_AAAA_AAAA_AAAA_AAAA_AAAA_AAAA & __bf_max,
+                        ]
+                    }
+
+                    fn __bf_ones(__bf_bytes: &[::core::primitive::u8]) -> ::core::primitive::u32 {
+                        __bf_bytes.iter().map(|__bf_byte| __bf_byte.count_ones()).sum()
+                    }
+
+                    #( #checks )*
+                    for __bf_this in [Self::ZERO, Self::ONES] {
+                        ::core::assert!(#from_bytes.bytes == __bf_this.bytes, "bytes did not round trip");
+                    }
+                }
             }
-        );
-        Some(getters)
+        ))
     }
 
-    fn expand_setters_for_field(
-        &self,
-        offset: &TokenStream2,
-        info: &FieldInfo<'_>,
-    ) -> Option<TokenStream2> {
-        let FieldInfo {
-            index: _,
-            field,
-            config,
-        } = info;
-        if config.skip_setters() {
+    /// Generates a module with a marker type per field and the `Field` impls keyed by them.
+    ///
+    /// Fields with skipped getters or setters do not get a marker type.
+    /// Since `Field` accessors cannot fail nothing is generated for `fallible = true` bitfields.
+    fn expand_field_trait_impls(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.field_trait.as_ref()?.span;
+        if config.fallible_enabled() {
             return None;
         }
-        let struct_ident = &self.item_struct.ident;
-        let span = field.span();
-        let retained_attrs = &config.retained_attrs;
-
-        let ident = info.ident_frag();
-        let name = info.name();
-        let ty = &field.ty;
-        let vis = &field.vis;
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let (impl_generics, ty_generics, where_clause) = self.item_struct.generics.split_for_impl();
+        let mod_ident = format_ident!("{}_fields", to_snake_case(&ident.unraw().to_string()));
+        // Restricted visibilities are relative to the module of the struct and thus cannot
+        // be used as is within the generated module.
+        let marker_vis = if matches!(vis, syn::Visibility::Public(_)) {
+            quote_spanned!(span=> pub)
+        } else {
+            quote_spanned!(span=> pub(crate))
+        };
+        let mod_docs = format!("Marker types to address the fields of [`{ident}`] via `Field`.");
+        let (markers, impls): (Vec<_>, Vec<_>) = self
+            .field_infos(config)
+            .filter(|info| !info.config.skip_getters() && !info.config.skip_setters())
+            .map(|info| {
+                let field = info.field;
+                let field_span = field.span();
+                let ty = &field.ty;
+                let field_ident = info.ident_frag();
+                // Field names such as `__` or `_0` have no valid camel case counterpart.
+                let fallback_marker = || format_ident!("Field{}", info.index);
+                let (marker_ident, get_ident) = match (&field.ident, &info.accessor) {
+                    (Some(ident), Some(accessor)) => (
+                        syn::parse_str::<syn::Ident>(&to_camel_case(&ident.unraw().to_string()))
+                            .map_or_else(|_| fallback_marker(), |mut marker| {
+                                marker.set_span(field_span);
+                                marker
+                            }),
+                        accessor.clone(),
+                    ),
+                    _ => (fallback_marker(), format_ident!("get_{}", field_ident)),
+                };
+                let set_ident = format_ident!("set_{}", field_ident);
+                let marker_docs = format!("Marker type for the `{}` field.", info.name());
+                let marker = quote_spanned!(field_span=>
+                    #[doc = #marker_docs]
+                    #marker_vis enum #marker_ident {}
+                );
+                let field_impl = quote_spanned!(field_span=>
+                    impl #impl_generics ::modular_bitfield::Field<#mod_ident::#marker_ident> for #ident #ty_generics #where_clause {
+                        type Value = <#ty as ::modular_bitfield::Specifier>::InOut;
 
-        let set_ident = format_ident!("set_{}", ident);
-        let set_checked_ident = format_ident!("set_{}_checked", ident);
-        let with_ident = format_ident!("with_{}", ident);
-        let with_checked_ident = format_ident!("with_{}_checked", ident);
+                        #[inline]
+                        fn read(&self) -> Self::Value {
+                            self.#get_ident()
+                        }
 
-        let set_assert_msg = format!("value out of bounds for field {struct_ident}.{name}");
-        let setter_docs = format!(
-            "Sets the value of `{name}` to the given value.\n\n\
-             # Panics\n\n\
-             If the given value is out of bounds for `{name}`.",
-        );
-        let checked_setter_docs = format!(
-            "Sets the value of `{name}` to the given value.\n\n\
-             # Errors\n\n\
-             If the given value is out of bounds for `{name}`.",
-        );
-        let with_docs = format!(
-            "Returns a copy of the bitfield with the value of `{name}` \
-             set to the given value.\n\n\
-             # Panics\n\n\
-             If the given value is out of bounds for `{name}`.",
-        );
-        let checked_with_docs = format!(
-            "Returns a copy of the bitfield with the value of `{name}` \
-             set to the given value.\n\n\
-             # Errors\n\n\
-             If the given value is out of bounds for `{name}`.",
-        );
-        let setters = quote_spanned!(span=>
-            #[doc = #with_docs]
-            #[inline]
-            #[allow(dead_code)]
-            #[must_use]
-            #( #retained_attrs )*
-            #vis fn #with_ident(
-                mut self,
-                new_val: <#ty as ::modular_bitfield::Specifier>::InOut
-            ) -> Self {
-                self.#set_ident(new_val);
-                self
+                        #[inline]
+                        fn write(&mut self, value: Self::Value) {
+                            self.#set_ident(value)
+                        }
+                    }
+                );
+                (marker, field_impl)
+            })
+            .unzip();
+        Some(quote_spanned!(span=>
+            #[doc = #mod_docs]
+            #vis mod #mod_ident {
+                #( #markers )*
             }
 
-            #[doc = #checked_with_docs]
-            #[inline]
-            #[allow(dead_code)]
-            #( #retained_attrs )*
-            #vis fn #with_checked_ident(
-                mut self,
-                new_val: <#ty as ::modular_bitfield::Specifier>::InOut,
-            ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
-                self.#set_checked_ident(new_val)?;
-                ::core::result::Result::Ok(self)
+            #( #impls )*
+        ))
+    }
+
+    /// Generates a struct per `#[group(Name)]` holding the values of its fields as well as
+    /// the accessors to get and set all of them at once.
+    ///
+    /// The group getter is only generated if no field of the group skips its getters
+    /// and likewise for the group setters.
+    fn expand_group_accessors(&self, config: &Config) -> TokenStream2 {
+        let mut groups = Vec::<(syn::Ident, Vec<FieldInfo<'_>>)>::new();
+        for info in self.field_infos(config) {
+            let Some(group) = info.config.group.as_ref() else {
+                continue;
+            };
+            match groups.last_mut() {
+                Some((name, members)) if *name == group.value => members.push(info),
+                _ => groups.push((group.value.clone(), vec![info])),
             }
+        }
+        let groups = groups
+            .iter()
+            .map(|(name, members)| self.expand_group(config, name, members));
+        let span = self.item_struct.span();
+        quote_spanned!(span=>
+            #( #groups )*
+        )
+    }
 
-            #[doc = #setter_docs]
-            #[inline]
-            #[allow(dead_code)]
-            #( #retained_attrs )*
-            #vis fn #set_ident(&mut self, new_val: <#ty as ::modular_bitfield::Specifier>::InOut) {
-                self.#set_checked_ident(new_val).expect(#set_assert_msg);
+    /// Generates the struct and accessors of a single `#[group(Name)]`.
+    fn expand_group(
+        &self,
+        config: &Config,
+        name: &syn::Ident,
+        members: &[FieldInfo<'_>],
+    ) -> TokenStream2 {
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let (impl_generics, ty_generics, where_clause) = self.item_struct.generics.split_for_impl();
+        let span = name.span();
+        let field_vis = &members[0].field.vis;
+        let field_idents = members
+            .iter()
+            .map(|info| info.field.ident.clone().expect("group fields are named"))
+            .collect::<Vec<_>>();
+        let field_names = field_idents
+            .iter()
+            .map(|ident| format!("`{ident}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let fields = members.iter().map(|info| {
+            let field = info.field;
+            let ty = &field.ty;
+            let vis = &field.vis;
+            let ident = &field.ident;
+            let docs = format!("The value of the `{}` field.", info.name());
+            quote_spanned!(field.span()=>
+                #[doc = #docs]
+                #vis #ident: <#ty as ::modular_bitfield::Specifier>::InOut
+            )
+        });
+        let get_ident = format_ident!("{}", to_snake_case(&name.unraw().to_string()), span = span);
+        let set_ident = format_ident!("set_{}", get_ident);
+        let with_ident = format_ident!("with_{}", get_ident);
+        let getters = members
+            .iter()
+            .map(|info| info.accessor.clone().expect("group fields are named"))
+            .collect::<Vec<_>>();
+        let setters = getters.iter().map(|ident| format_ident!("set_{}", ident));
+        let track_caller = Self::expand_track_caller(config, span);
+        let getter = members
+            .iter()
+            .all(|info| !info.config.skip_getters())
+            .then(|| {
+                let docs = format!(
+                    "Returns the values of the {field_names} fields as [`{name}`].\n\n\
+                 # Panics\n\n\
+                 If any of the values contains an invalid bit pattern.",
+                );
+                quote_spanned!(span=>
+                    #[doc = #docs]
+                    #[inline]
+                    #[must_use]
+                    #track_caller
+                    #field_vis fn #get_ident(&self) -> #name {
+                        #name {
+                            #( #field_idents: self.#getters(), )*
+                        }
+                    }
+                )
+            });
+        let setters = members
+            .iter()
+            .all(|info| !info.config.skip_setters())
+            .then(|| {
+                let set_docs = format!(
+                    "Sets the values of the {field_names} fields to the given [`{name}`].\n\n\
+                 # Panics\n\n\
+                 If any of the values is out of bounds for its field.",
+                );
+                let with_docs = format!(
+                    "Returns a copy of the bitfield with the values of the {field_names} \
+                 fields set to the given [`{name}`].\n\n\
+                 # Panics\n\n\
+                 If any of the values is out of bounds for its field.",
+                );
+                quote_spanned!(span=>
+                    #[doc = #set_docs]
+                    #[inline]
+                    #track_caller
+                    #field_vis fn #set_ident(&mut self, new_val: #name) {
+                        #( self.#setters(new_val.#field_idents); )*
+                    }
+
+                    #[doc = #with_docs]
+                    #[inline]
+                    #[must_use]
+                    #track_caller
+                    #field_vis fn #with_ident(mut self, new_val: #name) -> Self {
+                        self.#set_ident(new_val);
+                        self
+                    }
+                )
+            });
+        let struct_docs = format!("The values of the {field_names} fields of [`{ident}`].");
+        quote_spanned!(span=>
+            #[doc = #struct_docs]
+            #vis struct #name {
+                #( #fields, )*
             }
 
-            #[doc = #checked_setter_docs]
-            #[inline]
-            #( #retained_attrs )*
-            #vis fn #set_checked_ident(
-                &mut self,
-                new_val: <#ty as ::modular_bitfield::Specifier>::InOut
-            ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
-                const __BF_BASE_BITS: ::core::primitive::usize =
-                    ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::Bytes>() * 8;
-                const __BF_MAX_VALUE: <#ty as ::modular_bitfield::Specifier>::Bytes =
-                    !0 >> (__BF_BASE_BITS - <#ty as ::modular_bitfield::Specifier>::BITS);
-                let __bf_raw_val =
-                    <#ty as ::modular_bitfield::Specifier>::into_bytes(new_val)?;
-                // Value comparison to const guarantees the optimiser eliminates
-                // branching entirely when the maximum value is the same as the
-                // maximum value of the underlying type.
-                #[allow(clippy::absurd_extreme_comparisons)]
-                if __bf_raw_val <= __BF_MAX_VALUE {
-                    ::modular_bitfield::private::write_specifier::<#ty>(&mut self.bytes[..], #offset, __bf_raw_val);
-                    ::core::result::Result::Ok(())
-                } else {
-                    ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
-                }
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #getter
+                #setters
             }
-        );
-        Some(setters)
+        )
     }
 
-    fn expand_getters_and_setters_for_field(
-        &self,
-        offset: &mut Punctuated<syn::Expr, syn::Token![+]>,
-        info: &FieldInfo<'_>,
-    ) -> TokenStream2 {
-        let field = info.field;
-        let span = field.span();
-        let ty = &field.ty;
-        let offset_ts = if offset.is_empty() {
-            quote_spanned!(span=> 0)
-        } else {
-            offset.to_token_stream()
-        };
-        let getters = self.expand_getters_for_field(&offset_ts, info);
-        let setters = self.expand_setters_for_field(&offset_ts, info);
-        let getters_and_setters = quote_spanned!(span=>
-            #getters
-            #setters
-        );
-        offset.push(syn::parse_quote_spanned!(span=> <#ty as ::modular_bitfield::Specifier>::BITS));
-        getters_and_setters
+    /// Generates the compile-time checks that the fields placed via `#[at(bit = N)]`
+    /// neither overlap nor leave any bits uncovered.
+    ///
+    /// The macro already ensures that either all or none of the fields are placed
+    /// explicitly and that some field is placed at bit 0.
+    fn expand_position_checks(&self, config: &Config) -> Option<TokenStream2> {
+        let mut placed = self
+            .field_infos(config)
+            .map(|info| Some((info.config.at.as_ref()?.value, info)))
+            .collect::<Option<Vec<_>>>()?;
+        placed.sort_by_key(|(at, _)| *at);
+        let checks = placed.windows(2).map(|window| {
+            let [(at, info), (next_at, next_info)] = window else {
+                unreachable!("windows of 2 always have two elements")
+            };
+            let span = info.field.span();
+            let ty = &info.field.ty;
+            let (name, next_name) = (info.name(), next_info.name());
+            let overlap_msg = format!(
+                "field `{name}` at bit {at} overlaps field `{next_name}` at bit {next_at}"
+            );
+            let gap_msg = format!(
+                "bits between field `{name}` at bit {at} and field `{next_name}` at bit {next_at} are not covered by any field"
+            );
+            quote_spanned!(span=>
+                const _: () = {
+                    let __bf_end = #at + <#ty as ::modular_bitfield::Specifier>::BITS;
+                    ::core::assert!(__bf_end <= #next_at, #overlap_msg);
+                    ::core::assert!(__bf_end >= #next_at, #gap_msg);
+                };
+            )
+        });
+        let span = self.item_struct.span();
+        Some(quote_spanned!(span=>
+            #( #checks )*
+        ))
     }
 
     fn expand_getters_and_setters(&self, config: &Config) -> TokenStream2 {
@@ -713,17 +2860,168 @@ impl BitfieldStruct {
         let bits_checks = self
             .field_infos(config)
             .map(|field_info| Self::expand_bits_checks_for_field(field_info));
-        let setters_and_getters = self
-            .field_infos(config)
-            .map(|field_info| self.expand_getters_and_setters_for_field(&mut offset, &field_info));
+        let setters_and_getters = self.field_infos(config).map(|field_info| {
+            self.expand_getters_and_setters_for_field(config, &mut offset, &field_info)
+        });
+        let position_checks = self.expand_position_checks(config);
+        let is_well_formed = self.expand_is_well_formed(config);
         quote_spanned!(span=>
             const _: () = {
                 #( #bits_checks )*
+                #position_checks
             };
 
             impl #impl_generics #ident #ty_generics #where_clause {
                 #( #setters_and_getters )*
+                #is_well_formed
             }
         )
     }
 }
+
+/// Returns the name of the given type if it may refer to a built-in specifier or primitive.
+///
+/// Only unqualified names and paths through the modules defining them are considered since
+/// other paths such as `regs::B2` may refer to user defined specifiers of the same name.
+fn builtin_type_ident(ty: &syn::Type) -> Option<&syn::Ident> {
+    let syn::Type::Path(syn::TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+    if path
+        .segments
+        .iter()
+        .any(|segment| !segment.arguments.is_empty())
+    {
+        return None;
+    }
+    let idents = path
+        .segments
+        .iter()
+        .map(|segment| &segment.ident)
+        .collect::<Vec<_>>();
+    match idents[..] {
+        [ident] => Some(ident),
+        [krate, module, ident]
+            if ((krate == "core" || krate == "std") && module == "primitive")
+                || (krate == "modular_bitfield"
+                    && (module == "specifiers" || module == "prelude")) =>
+        {
+            Some(ident)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the interface type of the given field type if it is one of the built-in specifiers.
+///
+/// Trait impls can not name `<T as Specifier>::InOut` of a foreign specifier `T` without
+/// conflicting with the blanket `impl<T> From<T> for T`, so for the built-in specifiers the
+/// interface type is resolved directly. Since an unqualified name may still refer to a user
+/// defined specifier, generated code relying on it must include [`builtin_in_out_check`].
+fn builtin_in_out_type(ty: &syn::Type) -> Option<TokenStream2> {
+    let ident = builtin_type_ident(ty)?;
+    if is_bool_type(ty) {
+        return Some(quote_spanned!(ident.span()=> ::core::primitive::bool));
+    }
+    let name = ident.to_string();
+    let bits = match name.as_str() {
+        "u8" | "u16" | "u32" | "u64" | "u128" => {
+            return Some(quote_spanned!(ident.span()=> ::core::primitive::#ident));
+        }
+        _ => builtin_specifier_bits(ty)?,
+    };
+    let prim = match bits {
+        1..=8 => "u8",
+        9..=16 => "u16",
+        17..=32 => "u32",
+        33..=64 => "u64",
+        _ => "u128",
+    };
+    let prim = syn::Ident::new(prim, ident.span());
+    Some(quote_spanned!(prim.span()=> ::core::primitive::#prim))
+}
+
+/// Generates a compile-time check that the interface type of the given field type is the
+/// interface type `prim` returned by [`builtin_in_out_type`].
+///
+/// This turns a user defined specifier shadowing the name of a built-in one into a
+/// compilation error instead of silently using the wrong interface type.
+fn builtin_in_out_check(ty: &syn::Type, prim: &TokenStream2) -> TokenStream2 {
+    quote_spanned!(ty.span()=>
+        const _: fn(<#ty as ::modular_bitfield::Specifier>::InOut) -> #prim = |__bf_value| __bf_value;
+    )
+}
+
+/// Returns the bit width `N` if the given type syntactically refers to a `BN` specifier.
+fn builtin_specifier_bits(ty: &syn::Type) -> Option<usize> {
+    let bits = builtin_type_ident(ty)?
+        .to_string()
+        .strip_prefix('B')?
+        .parse::<usize>()
+        .ok()?;
+    (1..=128).contains(&bits).then_some(bits)
+}
+
+/// Returns the bit width if the given type syntactically refers to `bool` or an unsigned
+/// primitive integer type.
+fn builtin_primitive_bits(ty: &syn::Type) -> Option<usize> {
+    let syn::Type::Path(syn::TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+    match path.get_ident()?.to_string().as_str() {
+        "bool" => Some(1),
+        "u8" => Some(8),
+        "u16" => Some(16),
+        "u32" => Some(32),
+        "u64" => Some(64),
+        "u128" => Some(128),
+        _ => None,
+    }
+}
+
+/// Converts the given `CamelCase` identifier into `snake_case`.
+pub(super) fn to_snake_case(ident: &str) -> String {
+    let mut snake = String::with_capacity(ident.len() + 4);
+    let mut prev_lower = false;
+    for c in ident.chars() {
+        if c.is_uppercase() {
+            if prev_lower {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+            prev_lower = false;
+        } else {
+            snake.push(c);
+            prev_lower = c.is_lowercase() || c.is_ascii_digit();
+        }
+    }
+    snake
+}
+
+/// Returns `true` if the meta is a `repr(..)`, possibly nested in `cfg_attr(..)`.
+fn is_repr_meta(meta: &syn::Meta) -> bool {
+    if meta.path().is_ident("repr") {
+        return true;
+    }
+    let syn::Meta::List(list) = meta else {
+        return false;
+    };
+    // The first argument of `cfg_attr(predicate, attrs..)` is the predicate.
+    list.path.is_ident("cfg_attr")
+        && list
+            .parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)
+            .is_ok_and(|metas| metas.iter().skip(1).any(is_repr_meta))
+}
+
+/// Converts the given `snake_case` identifier into `CamelCase`.
+fn to_camel_case(ident: &str) -> String {
+    ident
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .flat_map(|part| {
+            let mut chars = part.chars();
+            let first = chars.next().map(|c| c.to_ascii_uppercase());
+            first.into_iter().chain(chars)
+        })
+        .collect()
+}
@@ -10,6 +10,18 @@ pub struct FieldConfig {
     pub bits: Option<ConfigValue<usize>>,
     /// An encountered `#[skip]` attribute on a field.
     pub skip: Option<ConfigValue<SkipWhich>>,
+    /// An encountered `#[padding(N)]` attribute on a field.
+    pub padding: Option<ConfigValue<usize>>,
+    /// An encountered `#[group(Name)]` attribute on a field.
+    pub group: Option<ConfigValue<syn::Ident>>,
+    /// An encountered `#[at(bit = N)]` attribute on a field.
+    pub at: Option<ConfigValue<usize>>,
+    /// An encountered `#[expect_value = value]` attribute on a field.
+    pub expect_value: Option<ConfigValue<syn::Expr>>,
+    /// An encountered `#[bit_reverse]` attribute on a field.
+    pub bit_reverse: Option<ConfigValue<()>>,
+    /// An encountered `#[signed]` attribute on a field.
+    pub signed: Option<ConfigValue<()>>,
 }
 
 /// Controls which parts of the code generation to skip.
@@ -77,6 +89,132 @@ impl FieldConfig {
         Ok(())
     }
 
+    /// Sets the `#[padding(N)]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[padding(N)]`.
+    pub fn padding(&mut self, amount: usize, span: Span) -> Result<(), syn::Error> {
+        match self.padding {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[padding(N)]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[padding(N)]` here")))
+            }
+            None => self.padding = Some(ConfigValue::new(amount, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[group(Name)]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[group(Name)]`.
+    pub fn group(&mut self, name: syn::Ident, span: Span) -> Result<(), syn::Error> {
+        match self.group {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[group(Name)]` attribute for field"
+                )
+                .into_combine(format_err!(
+                    previous.span,
+                    "duplicate `#[group(Name)]` here"
+                )))
+            }
+            None => self.group = Some(ConfigValue::new(name, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[at(bit = N)]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[at(bit = N)]`.
+    pub fn at(&mut self, bit: usize, span: Span) -> Result<(), syn::Error> {
+        match self.at {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[at(bit = N)]` attribute for field"
+                )
+                .into_combine(format_err!(
+                    previous.span,
+                    "duplicate `#[at(bit = N)]` here"
+                )))
+            }
+            None => self.at = Some(ConfigValue::new(bit, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[expect_value = value]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[expect_value = value]`.
+    pub fn expect_value(&mut self, value: syn::Expr, span: Span) -> Result<(), syn::Error> {
+        match self.expect_value {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[expect_value = value]` attribute for field"
+                )
+                .into_combine(format_err!(
+                    previous.span,
+                    "duplicate `#[expect_value = value]` here"
+                )))
+            }
+            None => self.expect_value = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[bit_reverse]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[bit_reverse]`.
+    pub fn bit_reverse(&mut self, span: Span) -> Result<(), syn::Error> {
+        match self.bit_reverse {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[bit_reverse]` attribute for field"
+                )
+                .into_combine(format_err!(
+                    previous.span,
+                    "duplicate `#[bit_reverse]` here"
+                )))
+            }
+            None => self.bit_reverse = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[signed]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[signed]`.
+    pub fn signed(&mut self, span: Span) -> Result<(), syn::Error> {
+        match self.signed {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[signed]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[signed]` here")))
+            }
+            None => self.signed = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
     /// Sets the `#[skip(which)]` if found for a `#[bitfield]` annotated field.
     ///
     /// # Syntax
@@ -120,6 +258,18 @@ impl FieldConfig {
         Ok(())
     }
 
+    /// Merges a struct-level skip, e.g. from `#[bitfield(setters = false)]`, into the field config.
+    ///
+    /// Unlike [`FieldConfig::skip`] this never fails since overlaps with field-level
+    /// `#[skip]` attributes are redundant but harmless.
+    pub fn inherit_skip(&mut self, which: SkipWhich, span: Span) {
+        let value = match self.skip.as_ref().map(|previous| previous.value) {
+            Some(previous) if previous != which => SkipWhich::All,
+            _ => which,
+        };
+        self.skip = Some(ConfigValue { value, span });
+    }
+
     /// Returns `true` if the config demands that code generation for setters should be skipped.
     pub fn skip_setters(&self) -> bool {
         self.skip
@@ -1,4 +1,8 @@
-use super::{field_config::FieldConfig, BitfieldStruct, Config};
+use super::{
+    config::ConfigValue,
+    field_config::{FieldConfig, SkipWhich},
+    BitfieldStruct, Config,
+};
 
 /// Compactly stores all shared and useful information about a single `#[bitfield]` field.
 pub struct FieldInfo<'a> {
@@ -8,6 +12,10 @@ pub struct FieldInfo<'a> {
     pub field: &'a syn::Field,
     /// The configuration of the field.
     pub config: FieldConfig,
+    /// The identifier the accessor names of a named field are derived from.
+    ///
+    /// This is the field identifier unless renamed via `rename_accessors`.
+    pub accessor: Option<syn::Ident>,
 }
 
 impl<'a> FieldInfo<'a> {
@@ -17,12 +25,13 @@ impl<'a> FieldInfo<'a> {
             index: id,
             field,
             config,
+            accessor: field.ident.clone(),
         }
     }
 
-    /// Returns the ident fragment for this field.
+    /// Returns the ident fragment the accessor names of this field are derived from.
     pub fn ident_frag(&self) -> &dyn quote::IdentFragment {
-        match &self.field.ident {
+        match &self.accessor {
             Some(ident) => ident,
             None => &self.index,
         }
@@ -58,13 +67,23 @@ impl BitfieldStruct {
         config: &'b Config,
     ) -> impl Iterator<Item = FieldInfo<'a>> {
         Self::fields(&self.item_struct).map(move |(n, field)| {
-            let field_config = config
+            let mut field_config = config
                 .field_configs
                 .get(&n)
                 .map(|config| &config.value)
                 .cloned()
                 .unwrap_or_default();
-            FieldInfo::new(n, field, field_config)
+            if let Some(getters @ ConfigValue { value: false, .. }) = &config.getters {
+                field_config.inherit_skip(SkipWhich::Getters, getters.span);
+            }
+            if let Some(setters @ ConfigValue { value: false, .. }) = &config.setters {
+                field_config.inherit_skip(SkipWhich::Setters, setters.span);
+            }
+            let mut info = FieldInfo::new(n, field, field_config);
+            if let Some(rename) = &config.rename_accessors {
+                info.accessor = info.accessor.map(|ident| rename.value.apply(&ident));
+            }
+            info
         })
     }
 }
@@ -8,7 +8,8 @@ mod params;
 use self::{config::Config, params::ParamArgs};
 use core::convert::TryFrom;
 use proc_macro2::{Span, TokenStream as TokenStream2};
-use syn::{self, parse::Result};
+use quote::quote;
+use syn::{self, parse::Result, punctuated::Punctuated, Token};
 
 /// Analyzes the given token stream for `#[bitfield]` properties and expands code if valid.
 pub fn analyse_and_expand(args: TokenStream2, input: TokenStream2) -> TokenStream2 {
@@ -25,6 +26,9 @@ pub fn analyse_and_expand(args: TokenStream2, input: TokenStream2) -> TokenStrea
 /// If the given token stream does not yield a valid `#[bitfield]` specifier.
 fn analyse_and_expand_or_error(args: TokenStream2, input: TokenStream2) -> Result<TokenStream2> {
     let input = syn::parse2::<syn::ItemStruct>(input)?;
+    if let Some(expanded) = expand_cfg_field(&args, &input) {
+        return Ok(expanded);
+    }
     let params = syn::parse2::<ParamArgs>(args)?;
     let mut config = Config::default();
     config.feed_params(params)?;
@@ -32,6 +36,65 @@ fn analyse_and_expand_or_error(args: TokenStream2, input: TokenStream2) -> Resul
     Ok(bitfield.expand(&config))
 }
 
+/// Splits a struct with a `#[cfg(..)]` guarded field into a copy with and a copy without
+/// that field.
+///
+/// Both copies are guarded by the predicate of the field and re-invoke `#[bitfield]` so
+/// that offsets and sizes are computed only for the fields that are actually present.
+/// Structs with multiple guarded fields are split recursively.
+///
+/// Returns `None` if no field is guarded by `#[cfg(..)]`.
+fn expand_cfg_field(args: &TokenStream2, input: &syn::ItemStruct) -> Option<TokenStream2> {
+    let is_cfg = |attr: &syn::Attribute| attr.path().is_ident("cfg");
+    let index = input
+        .fields
+        .iter()
+        .position(|field| field.attrs.iter().any(is_cfg))?;
+    let predicates = input
+        .fields
+        .iter()
+        .nth(index)?
+        .attrs
+        .iter()
+        .filter_map(|attr| {
+            is_cfg(attr)
+                .then(|| attr.meta.require_list().ok())
+                .flatten()
+                .map(|list| list.tokens.clone())
+        })
+        .collect::<Vec<_>>();
+    let predicate = match &predicates[..] {
+        [predicate] => predicate.clone(),
+        predicates => quote!(all(#( #predicates ),*)),
+    };
+    let mut with_field = input.clone();
+    if let Some(field) = with_field.fields.iter_mut().nth(index) {
+        field.attrs.retain(|attr| !is_cfg(attr));
+    }
+    let mut without_field = input.clone();
+    let remove_field = |fields: &mut Punctuated<syn::Field, Token![,]>| {
+        *fields = core::mem::take(fields)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(n, field)| (n != index).then_some(field))
+            .collect();
+    };
+    match &mut without_field.fields {
+        syn::Fields::Named(fields) => remove_field(&mut fields.named),
+        syn::Fields::Unnamed(fields) => remove_field(&mut fields.unnamed),
+        syn::Fields::Unit => {}
+    }
+    Some(quote!(
+        #[cfg(#predicate)]
+        #[::modular_bitfield::bitfield(#args)]
+        #with_field
+
+        #[cfg(not(#predicate))]
+        #[::modular_bitfield::bitfield(#args)]
+        #without_field
+    ))
+}
+
 /// Type used to guide analysis and expansion of `#[bitfield]` structs.
 struct BitfieldStruct {
     /// The input `struct` item.
@@ -48,3 +111,22 @@ fn raise_skip_error(skip_params: &str, span: Span, previous: Span) -> Result<()>
         format_err!(previous, "duplicate `#[skip{}]` here", skip_params),
     ))
 }
+
+/// Returns `true` if the given type syntactically refers to the primitive `bool` type.
+fn is_bool_type(ty: &syn::Type) -> bool {
+    let syn::Type::Path(syn::TypePath { qself: None, path }) = ty else {
+        return false;
+    };
+    let idents = path
+        .segments
+        .iter()
+        .map(|segment| &segment.ident)
+        .collect::<Vec<_>>();
+    match idents[..] {
+        [ident] => ident == "bool",
+        [krate, module, ident] => {
+            (krate == "core" || krate == "std") && module == "primitive" && ident == "bool"
+        }
+        _ => false,
+    }
+}
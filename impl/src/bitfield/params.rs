@@ -1,15 +1,52 @@
-use super::config::Config;
+use super::config::{BitsValue, Config, RenameAccessors};
 use proc_macro2::Span;
-use syn::{parse::Result, spanned::Spanned};
+use syn::{
+    parse::{discouraged::Speculative as _, Result},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Token,
+};
 
 /// The parameters given to the `#[bitfield]` proc. macro.
 pub struct ParamArgs {
-    args: Vec<syn::MetaNameValue>,
+    args: Vec<syn::Meta>,
+}
+
+/// The `#[bitfield]` parameters that are flags and thus do not take a value.
+const FLAG_PARAMS: &[&str] = &[
+    "track_caller",
+    "warn_waste",
+    "pad_to_pow2",
+    "doc_layout",
+    "copy",
+    "index",
+    "prop_test",
+    "field_trait",
+    "field_layout",
+    "set_masked",
+    "raw_setters",
+    "ref_getters",
+    "as_getters",
+];
+
+/// Parses a single `#[bitfield]` parameter.
+///
+/// All parameters are of the form `name = value` except for the flag parameters.
+fn parse_param(input: syn::parse::ParseStream<'_>) -> Result<syn::Meta> {
+    let fork = input.fork();
+    if let Ok(path) = fork.call(syn::Path::parse_mod_style) {
+        let is_flag = FLAG_PARAMS.iter().any(|flag| path.is_ident(flag));
+        if is_flag && (fork.is_empty() || fork.peek(Token![,])) {
+            input.advance_to(&fork);
+            return Ok(syn::Meta::Path(path));
+        }
+    }
+    input.parse().map(syn::Meta::NameValue)
 }
 
 impl syn::parse::Parse for ParamArgs {
     fn parse(input: syn::parse::ParseStream<'_>) -> Result<Self> {
-        let punctuated = <syn::punctuated::Punctuated<_, syn::Token![,]>>::parse_terminated(input)?;
+        let punctuated = Punctuated::<_, Token![,]>::parse_terminated_with(input, parse_param)?;
         Ok(Self {
             args: punctuated.into_iter().collect(),
         })
@@ -17,8 +54,8 @@ impl syn::parse::Parse for ParamArgs {
 }
 
 impl IntoIterator for ParamArgs {
-    type Item = syn::MetaNameValue;
-    type IntoIter = std::vec::IntoIter<syn::MetaNameValue>;
+    type Item = syn::Meta;
+    type IntoIter = std::vec::IntoIter<syn::Meta>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.args.into_iter()
@@ -64,31 +101,143 @@ impl Config {
         Self::feed_int_param(name_value, "bytes", |value, span| self.bytes(value, span))
     }
 
-    /// Feeds a `bytes: int` parameter to the `#[bitfield]` configuration.
+    /// Feeds a `bits: int` parameter to the `#[bitfield]` configuration.
+    ///
+    /// Besides integer literals this also accepts constant expressions such as `bits = MY_BITS`
+    /// and ranges such as `bits = 16..=32`.
     fn feed_bits_param(&mut self, name_value: &syn::MetaNameValue) -> Result<()> {
-        Self::feed_int_param(name_value, "bits", |value, span| self.bits(value, span))
+        match &name_value.value {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(_),
+                ..
+            }) => Self::feed_int_param(name_value, "bits", |value, span| {
+                self.bits(BitsValue::Int(value), span)
+            }),
+            syn::Expr::Lit(_) => Err(format_err!(
+                name_value.value,
+                "encountered invalid value argument for #[bitfield] `bits` parameter"
+            )),
+            syn::Expr::Range(range) => {
+                self.bits(BitsValue::Range(range.clone()), name_value.span())
+            }
+            expr => self.bits(BitsValue::Expr(expr.clone()), name_value.span()),
+        }
+    }
+
+    /// Feeds a parameter that takes a boolean value to the `#[bitfield]` configuration.
+    fn feed_bool_param<F>(name_value: &syn::MetaNameValue, name: &str, on_success: F) -> Result<()>
+    where
+        F: FnOnce(bool, Span) -> Result<()>,
+    {
+        assert!(name_value.path.is_ident(name));
+        match &name_value.value {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Bool(lit_bool),
+                ..
+            }) => {
+                on_success(lit_bool.value, name_value.span())?;
+            }
+            invalid => {
+                return Err(format_err!(
+                    invalid,
+                    "encountered invalid value argument for #[bitfield] `{}` parameter",
+                    name
+                ))
+            }
+        }
+        Ok(())
     }
 
     /// Feeds a `filled: bool` parameter to the `#[bitfield]` configuration.
     fn feed_filled_param(&mut self, name_value: &syn::MetaNameValue) -> Result<()> {
-        assert!(name_value.path.is_ident("filled"));
+        Self::feed_bool_param(name_value, "filled", |value, span| self.filled(value, span))
+    }
+
+    /// Feeds a `transparent: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_transparent_param(&mut self, name_value: &syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "transparent", |value, span| {
+            self.transparent(value, span)
+        })
+    }
+
+    /// Feeds a `fallible: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_fallible_param(&mut self, name_value: &syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "fallible", |value, span| {
+            self.fallible(value, span)
+        })
+    }
+
+    /// Feeds a `getters: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_getters_param(&mut self, name_value: &syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "getters", |value, span| {
+            self.getters(value, span)
+        })
+    }
+
+    /// Feeds a `setters: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_setters_param(&mut self, name_value: &syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "setters", |value, span| {
+            self.setters(value, span)
+        })
+    }
+
+    /// Feeds an `allow: str` parameter to the `#[bitfield]` configuration.
+    ///
+    /// The string contains a comma separated list of lints, e.g. `"clippy::foo, dead_code"`.
+    fn feed_allow_param(&mut self, name_value: &syn::MetaNameValue) -> Result<()> {
+        assert!(name_value.path.is_ident("allow"));
         match &name_value.value {
             syn::Expr::Lit(syn::ExprLit {
-                lit: syn::Lit::Bool(lit_bool),
+                lit: syn::Lit::Str(lit_str),
                 ..
             }) => {
-                self.filled(lit_bool.value, name_value.span())?;
+                let lints = lit_str
+                    .parse_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+                    .map_err(|err| {
+                        format_err!(
+                            lit_str,
+                            "encountered malformatted lints for `allow` parameter: {}",
+                            err
+                        )
+                    })?;
+                self.allow(lints);
             }
             invalid => {
                 return Err(format_err!(
                     invalid,
-                    "encountered invalid value argument for #[bitfield] `filled` parameter",
+                    "encountered invalid value argument for #[bitfield] `allow` parameter",
                 ))
             }
         }
         Ok(())
     }
 
+    /// Feeds a `rename_accessors: str` parameter to the `#[bitfield]` configuration.
+    ///
+    /// The only supported case is `"snake"`.
+    fn feed_rename_accessors_param(&mut self, name_value: &syn::MetaNameValue) -> Result<()> {
+        assert!(name_value.path.is_ident("rename_accessors"));
+        match &name_value.value {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit_str),
+                ..
+            }) if lit_str.value() == "snake" => {
+                self.rename_accessors(RenameAccessors::Snake, name_value.span())
+            }
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit_str),
+                ..
+            }) => Err(format_err!(
+                lit_str,
+                "encountered unsupported case for `rename_accessors` parameter: expected \"snake\"",
+            )),
+            invalid => Err(format_err!(
+                invalid,
+                "encountered invalid value argument for #[bitfield] `rename_accessors` parameter",
+            )),
+        }
+    }
+
     /// Feeds the given parameters to the `#[bitfield]` configuration.
     ///
     /// # Errors
@@ -96,15 +245,88 @@ impl Config {
     /// If a parameter is malformatted, unexpected, duplicate or in conflict.
     pub fn feed_params<'a, P>(&mut self, params: P) -> Result<()>
     where
-        P: IntoIterator<Item = syn::MetaNameValue> + 'a,
+        P: IntoIterator<Item = syn::Meta> + 'a,
     {
-        for name_value in params {
+        for meta in params {
+            let name_value = match meta {
+                syn::Meta::Path(path) if path.is_ident("track_caller") => {
+                    self.track_caller(path.span())?;
+                    continue;
+                }
+                syn::Meta::Path(path) if path.is_ident("warn_waste") => {
+                    self.warn_waste(path.span())?;
+                    continue;
+                }
+                syn::Meta::Path(path) if path.is_ident("pad_to_pow2") => {
+                    self.pad_to_pow2(path.span())?;
+                    continue;
+                }
+                syn::Meta::Path(path) if path.is_ident("doc_layout") => {
+                    self.doc_layout(path.span())?;
+                    continue;
+                }
+                syn::Meta::Path(path) if path.is_ident("copy") => {
+                    self.copy(path.span())?;
+                    continue;
+                }
+                syn::Meta::Path(path) if path.is_ident("index") => {
+                    self.index(path.span())?;
+                    continue;
+                }
+                syn::Meta::Path(path) if path.is_ident("prop_test") => {
+                    self.prop_test(path.span())?;
+                    continue;
+                }
+                syn::Meta::Path(path) if path.is_ident("field_trait") => {
+                    self.field_trait(path.span())?;
+                    continue;
+                }
+                syn::Meta::Path(path) if path.is_ident("field_layout") => {
+                    self.field_layout(path.span())?;
+                    continue;
+                }
+                syn::Meta::Path(path) if path.is_ident("set_masked") => {
+                    self.set_masked(path.span())?;
+                    continue;
+                }
+                syn::Meta::Path(path) if path.is_ident("raw_setters") => {
+                    self.raw_setters(path.span())?;
+                    continue;
+                }
+                syn::Meta::Path(path) if path.is_ident("ref_getters") => {
+                    self.ref_getters(path.span())?;
+                    continue;
+                }
+                syn::Meta::Path(path) if path.is_ident("as_getters") => {
+                    self.as_getters(path.span())?;
+                    continue;
+                }
+                syn::Meta::NameValue(name_value) => name_value,
+                unsupported => {
+                    return Err(format_err!(
+                        unsupported,
+                        "encountered unsupported #[bitfield] attribute"
+                    ))
+                }
+            };
             if name_value.path.is_ident("bytes") {
                 self.feed_bytes_param(&name_value)?;
             } else if name_value.path.is_ident("bits") {
                 self.feed_bits_param(&name_value)?;
             } else if name_value.path.is_ident("filled") {
                 self.feed_filled_param(&name_value)?;
+            } else if name_value.path.is_ident("transparent") {
+                self.feed_transparent_param(&name_value)?;
+            } else if name_value.path.is_ident("fallible") {
+                self.feed_fallible_param(&name_value)?;
+            } else if name_value.path.is_ident("getters") {
+                self.feed_getters_param(&name_value)?;
+            } else if name_value.path.is_ident("setters") {
+                self.feed_setters_param(&name_value)?;
+            } else if name_value.path.is_ident("allow") {
+                self.feed_allow_param(&name_value)?;
+            } else if name_value.path.is_ident("rename_accessors") {
+                self.feed_rename_accessors_param(&name_value)?;
             } else {
                 return Err(format_err!(
                     name_value,
@@ -1,6 +1,6 @@
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote_spanned;
-use syn::spanned::Spanned as _;
+use quote::{quote_spanned, ToTokens as _};
+use syn::{ext::IdentExt as _, spanned::Spanned as _};
 
 pub fn generate(input: TokenStream2) -> TokenStream2 {
     match generate_or_error(input) {
@@ -21,6 +21,18 @@ fn generate_or_error(input: TokenStream2) -> syn::Result<TokenStream2> {
             brace_token: data_enum.brace_token,
             variants: data_enum.variants,
         }),
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Unnamed(ref fields),
+            ..
+        }) if fields.unnamed.len() == 1 => generate_newtype(&input, &fields.unnamed[0]),
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Unnamed(_),
+            ..
+        }) => Err(format_err!(
+            input,
+            "tuple structs are only supported as bitfield specifiers \
+             if they are newtypes with a single unnamed field",
+        )),
         syn::Data::Struct(_) => Err(format_err!(
             input,
             "structs are not supported as bitfield specifiers",
@@ -31,36 +43,256 @@ fn generate_or_error(input: TokenStream2) -> syn::Result<TokenStream2> {
         )),
     }
 }
+
+/// Returns the `#[specifier(validate = path)]` attribute of a newtype specifier if any.
+fn parse_newtype_attrs(attrs: &[syn::Attribute]) -> syn::Result<Option<syn::Path>> {
+    let mut validate = None;
+    for attr in attrs {
+        if attr.path().is_ident("bits") {
+            return Err(format_err_spanned!(
+                attr,
+                "newtype specifiers take the bits of their field and do not support a 'bits' attribute",
+            ));
+        }
+        if !attr.path().is_ident("specifier") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("validate") {
+                return Err(
+                    meta.error("encountered unsupported #[specifier] argument for a newtype")
+                );
+            }
+            if validate.is_some() {
+                return Err(meta.error("duplicate #[specifier(validate)] attribute"));
+            }
+            validate = Some(meta.value()?.parse::<syn::Path>()?);
+            Ok(())
+        })?;
+    }
+    Ok(validate)
+}
+
+/// Generates the `Specifier` impl of a newtype forwarding to the specifier of its field.
+///
+/// The field type must be its own interface type, e.g. `u8`, `bool`, a `Specifier` enum
+/// or a `#[bitfield]` struct. With `#[specifier(validate = path)]` the function at `path`
+/// is called with a reference to the field value and values it rejects are treated as
+/// out of bounds or invalid bit patterns.
+fn generate_newtype(input: &syn::DeriveInput, field: &syn::Field) -> syn::Result<TokenStream2> {
+    let span = input.span();
+    let validate = parse_newtype_attrs(&input.attrs)?;
+    let ident = &input.ident;
+    let inner = &field.ty;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let where_predicates = where_clause.map(|where_clause| &where_clause.predicates);
+    let (copy_bound, validate_into, validate_from) = match &validate {
+        Some(validate) => (
+            Some(quote_spanned!(validate.span()=>
+                <#inner as ::modular_bitfield::Specifier>::Bytes: ::core::marker::Copy,
+            )),
+            Some(quote_spanned!(validate.span()=>
+                if !#validate(&input.0) {
+                    return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds);
+                }
+            )),
+            Some(quote_spanned!(validate.span()=>
+                if !#validate(&__bf_inner) {
+                    return ::core::result::Result::Err(
+                        ::modular_bitfield::error::InvalidBitPattern::new(bytes),
+                    );
+                }
+            )),
+        ),
+        None => (None, None, None),
+    };
+    Ok(quote_spanned!(span=>
+        impl #impl_generics ::modular_bitfield::Specifier for #ident #ty_generics
+        where
+            #copy_bound
+            #where_predicates
+        {
+            const BITS: ::core::primitive::usize = <#inner as ::modular_bitfield::Specifier>::BITS;
+            type Bytes = <#inner as ::modular_bitfield::Specifier>::Bytes;
+            type InOut = Self;
+
+            #[inline]
+            fn into_bytes(input: <Self as ::modular_bitfield::Specifier>::InOut) -> ::core::result::Result<<Self as ::modular_bitfield::Specifier>::Bytes, ::modular_bitfield::error::OutOfBounds> {
+                #validate_into
+                <#inner as ::modular_bitfield::Specifier>::into_bytes(input.0)
+            }
+
+            #[inline]
+            fn from_bytes(bytes: <Self as ::modular_bitfield::Specifier>::Bytes) -> ::core::result::Result<<Self as ::modular_bitfield::Specifier>::InOut, ::modular_bitfield::error::InvalidBitPattern<<Self as ::modular_bitfield::Specifier>::Bytes>> {
+                let __bf_inner: #inner = <#inner as ::modular_bitfield::Specifier>::from_bytes(bytes)?;
+                #validate_from
+                ::core::result::Result::Ok(Self(__bf_inner))
+            }
+        }
+    ))
+}
+
 struct Attributes {
-    bits: Option<usize>,
+    bits: Option<syn::Expr>,
+    binary: Option<proc_macro2::Span>,
+    unchecked: Option<proc_macro2::Span>,
+    from_str: Option<proc_macro2::Span>,
+    bool: Option<proc_macro2::Span>,
+    iter_variants: Option<proc_macro2::Span>,
+    min_max: Option<proc_macro2::Span>,
+}
+
+/// Returns the integer type of a `#[repr(..)]` attribute and its bit width if any.
+///
+/// The width of `isize` and `usize` is `None` since it depends on the target.
+fn parse_repr(attrs: &[syn::Attribute]) -> syn::Result<Option<(syn::Ident, Option<usize>)>> {
+    let mut repr = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            let width = match meta.path.get_ident().map(ToString::to_string).as_deref() {
+                Some("u8" | "i8") => Some(8),
+                Some("u16" | "i16") => Some(16),
+                Some("u32" | "i32") => Some(32),
+                Some("u64" | "i64") => Some(64),
+                Some("u128" | "i128") => Some(128),
+                Some("usize" | "isize") => None,
+                _ => {
+                    // Skip arguments of other representations such as `align(N)`.
+                    if meta.input.peek(syn::token::Paren) {
+                        let _arguments;
+                        syn::parenthesized!(_arguments in meta.input);
+                    }
+                    return Ok(());
+                }
+            };
+            if let Some(ident) = meta.path.get_ident() {
+                repr = Some((ident.clone(), width));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(repr)
+}
+
+/// Returns an error or a compile-time check if the `#[bits]` of the enum are wider than
+/// its `#[repr(..)]` integer type.
+fn check_repr_width(
+    attrs: &[syn::Attribute],
+    bits: Option<&syn::Expr>,
+) -> syn::Result<Option<TokenStream2>> {
+    let (Some((repr, Some(width))), Some(bits)) = (parse_repr(attrs)?, bits) else {
+        return Ok(None);
+    };
+    if let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Int(lit),
+        ..
+    }) = bits
+    {
+        let value = lit.base10_parse::<usize>()?;
+        if value > width {
+            return Err(format_err_spanned!(
+                bits,
+                "encountered #[bits = {}] wider than the {} bits of #[repr({})]",
+                value,
+                width,
+                repr,
+            ));
+        }
+        return Ok(None);
+    }
+    let message = format!("#[bits(..)] is wider than the {width} bits of #[repr({repr})]");
+    Ok(Some(quote_spanned!(bits.span()=>
+        const _: () = ::core::assert!((#bits) <= #width, #message);
+    )))
 }
 
 fn parse_attrs(attrs: &[syn::Attribute]) -> syn::Result<Attributes> {
-    let attributes = attrs
+    let mut attributes = attrs
         .iter()
         .filter(|attr| attr.path().is_ident("bits"))
-        .try_fold(Attributes { bits: None }, |mut acc, attr| {
-            if acc.bits.is_some() {
-                return Err(format_err_spanned!(
-                    attr,
-                    "More than one 'bits' attribute is not permitted",
-                ));
-            }
-            let meta = attr.meta.require_name_value()?;
-            acc.bits = if let syn::Expr::Lit(syn::ExprLit {
-                lit: syn::Lit::Int(lit),
-                ..
-            }) = &meta.value
-            {
-                Some(lit.base10_parse::<usize>()?)
+        .try_fold(
+            Attributes {
+                bits: None,
+                binary: None,
+                unchecked: None,
+                from_str: None,
+                bool: None,
+                iter_variants: None,
+                min_max: None,
+            },
+            |mut acc, attr| {
+                if acc.bits.is_some() {
+                    return Err(format_err_spanned!(
+                        attr,
+                        "More than one 'bits' attribute is not permitted",
+                    ));
+                }
+                acc.bits = match &attr.meta {
+                    syn::Meta::NameValue(syn::MetaNameValue {
+                        value:
+                            value @ syn::Expr::Lit(syn::ExprLit {
+                                lit: syn::Lit::Int(lit),
+                                ..
+                            }),
+                        ..
+                    }) => {
+                        lit.base10_parse::<usize>()?;
+                        Some(value.clone())
+                    }
+                    // Attribute values must be literals so constant expressions such
+                    // as `<B3 as Specifier>::BITS` are accepted in the `#[bits(..)]`
+                    // form and evaluated by the compiler.
+                    syn::Meta::List(list) => match list.parse_args::<syn::Expr>()? {
+                        syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Int(int),
+                            ..
+                        }) if !matches!(int.suffix(), "" | "usize") => {
+                            return Err(format_err_spanned!(
+                                int,
+                                "the bit width of #[bits({})] must be a `usize` but is a `{}` literal",
+                                int,
+                                int.suffix(),
+                            ))
+                        }
+                        expr => Some(expr),
+                    },
+                    _ => {
+                        return Err(format_err_spanned!(
+                            attr,
+                            "could not parse 'bits' attribute",
+                        ))
+                    }
+                };
+                Ok(acc)
+            },
+        )?;
+    for attr in attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("specifier"))
+    {
+        attr.parse_nested_meta(|meta| {
+            let (name, flag) = if meta.path.is_ident("binary") {
+                ("binary", &mut attributes.binary)
+            } else if meta.path.is_ident("unchecked") {
+                ("unchecked", &mut attributes.unchecked)
+            } else if meta.path.is_ident("from_str") {
+                ("from_str", &mut attributes.from_str)
+            } else if meta.path.is_ident("bool") {
+                ("bool", &mut attributes.bool)
+            } else if meta.path.is_ident("iter_variants") {
+                ("iter_variants", &mut attributes.iter_variants)
+            } else if meta.path.is_ident("min_max") {
+                ("min_max", &mut attributes.min_max)
             } else {
-                return Err(format_err_spanned!(
-                    attr,
-                    "could not parse 'bits' attribute",
-                ));
+                return Err(meta.error("encountered unsupported #[specifier] argument"));
             };
-            Ok(acc)
+            if flag.is_some() {
+                return Err(meta.error(format!("duplicate #[specifier({name})] attribute")));
+            }
+            *flag = Some(meta.path.span());
+            Ok(())
         })?;
+    }
     Ok(attributes)
 }
 
@@ -70,27 +302,16 @@ fn generate_enum(input: &syn::ItemEnum) -> syn::Result<TokenStream2> {
     let enum_ident = &input.ident;
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
-    let bits = if let Some(bits) = attributes.bits {
-        bits
-    } else {
-        let count_variants = input.variants.iter().count();
-        if !count_variants.is_power_of_two() {
-            return Err(format_err!(
-                span,
-                "#[derive(Specifier)] expected a number of variants which is a power of 2, specify #[bits = {}] if that was your intent",
-                count_variants.next_power_of_two().trailing_zeros(),
-            ));
-        }
-        // We can take `trailing_zeros` returns type as the required amount of bits.
-        if let Some(power_of_two) = count_variants.checked_next_power_of_two() {
-            power_of_two.trailing_zeros() as usize
-        } else {
-            return Err(format_err!(
-                span,
-                "#[derive(Specifier)] has too many variants to pack into a bitfield",
-            ));
-        }
-    };
+    let catch_all = find_catch_all(input)?;
+    if catch_all.is_some() && attributes.bits.is_none() {
+        return Err(format_err!(
+            span,
+            "#[derive(Specifier)] with a 'catch_all' variant requires a #[bits = N] attribute",
+        ));
+    }
+
+    let repr_check = check_repr_width(&input.attrs, attributes.bits.as_ref())?;
+    let (bits, bits_description) = enum_bits(input, attributes.bits)?;
 
     let variants = input
         .variants
@@ -100,31 +321,59 @@ fn generate_enum(input: &syn::ItemEnum) -> syn::Result<TokenStream2> {
             _ => None,
         })
         .collect::<Vec<_>>();
+    let patterns = match catch_all {
+        Some(_) => discriminant_patterns(input)?,
+        None => variants
+            .iter()
+            .map(|ident| quote_spanned!(ident.span()=> Self::#ident))
+            .collect(),
+    };
 
-    let check_discriminants = variants.iter().map(|ident| {
-        let span = ident.span();
-        quote_spanned!(span=>
-            impl #impl_generics ::modular_bitfield::private::checks::CheckDiscriminantInRange<
-                ::modular_bitfield::private::checks::BitCount<{Self::#ident as ::core::primitive::usize}>
-            > for #enum_ident #ty_generics #where_clause {
-                type CheckType = ::modular_bitfield::private::checks::BitCount<{
-                    ((Self::#ident as ::core::primitive::usize) < (1 << #bits)) as ::core::primitive::usize
-                }>;
-            }
-        )
-    });
-    let from_bytes_arms = variants.iter().map(|ident| {
+    let check_discriminants =
+        generate_discriminant_checks(input, &variants, &patterns, &bits, &bits_description)?;
+    let from_bytes_arms = variants.iter().zip(&patterns).map(|(ident, pattern)| {
         let span = ident.span();
         quote_spanned!(span=>
-            __bitfield_binding if __bitfield_binding == Self::#ident as <Self as ::modular_bitfield::Specifier>::Bytes => {
+            __bitfield_binding if __bitfield_binding == #pattern as <Self as ::modular_bitfield::Specifier>::Bytes => {
                 ::core::result::Result::Ok(Self::#ident)
             }
         )
     });
+    let (into_bytes_body, from_bytes_fallback) =
+        generate_conversion_bodies(span, catch_all, &variants, &patterns);
+    let min_max = attributes
+        .min_max
+        .map(|span| generate_min_max(span, input, &variants, &patterns))
+        .transpose()?;
+    let iter_variants = attributes
+        .iter_variants
+        .map(|span| generate_iter_variants(span, input, &variants));
+    let binary = attributes
+        .binary
+        .map(|span| generate_binary(span, input, catch_all, &variants, &patterns));
+    let from_bytes_unchecked = attributes
+        .unchecked
+        .map(|span| generate_from_bytes_unchecked(span, input, catch_all, &variants, &patterns))
+        .transpose()?;
+    let from_str = attributes
+        .from_str
+        .map(|span| generate_from_str(span, input, &variants));
+    let bool_conversions = attributes
+        .bool
+        .map(|span| generate_bool_conversions(span, input, catch_all, &variants))
+        .transpose()?;
 
     Ok(quote_spanned!(span=>
-        #( #check_discriminants )*
+        #repr_check
+        #check_discriminants
+        #min_max
+        #iter_variants
+        #binary
+        #from_bytes_unchecked
+        #from_str
+        #bool_conversions
 
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
         impl #impl_generics ::modular_bitfield::Specifier for #enum_ident #ty_generics #where_clause {
             const BITS: ::core::primitive::usize = #bits;
             type Bytes = <::modular_bitfield::private::checks::BitCount<#bits> as ::modular_bitfield::private::SpecifierBytes>::Bytes;
@@ -132,20 +381,548 @@ fn generate_enum(input: &syn::ItemEnum) -> syn::Result<TokenStream2> {
 
             #[inline]
             fn into_bytes(input: <Self as ::modular_bitfield::Specifier>::InOut) -> ::core::result::Result<<Self as ::modular_bitfield::Specifier>::Bytes, ::modular_bitfield::error::OutOfBounds> {
-                ::core::result::Result::Ok(input as <Self as ::modular_bitfield::Specifier>::Bytes)
+                #into_bytes_body
             }
 
             #[inline]
             fn from_bytes(bytes: <Self as ::modular_bitfield::Specifier>::Bytes) -> ::core::result::Result<<Self as ::modular_bitfield::Specifier>::InOut, ::modular_bitfield::error::InvalidBitPattern<<Self as ::modular_bitfield::Specifier>::Bytes>> {
                 match bytes {
                     #( #from_bytes_arms ),*
-                    invalid_bytes => {
-                        ::core::result::Result::Err(
-                            <::modular_bitfield::error::InvalidBitPattern<<Self as ::modular_bitfield::Specifier>::Bytes>>::new(invalid_bytes)
-                        )
-                    }
+                    #from_bytes_fallback
                 }
             }
         }
     ))
 }
+
+/// Generates the `iter_variants` associated function yielding all unit variants in
+/// declaration order for `#[specifier(iter_variants)]`.
+fn generate_iter_variants(
+    span: proc_macro2::Span,
+    input: &syn::ItemEnum,
+    variants: &[&syn::Ident],
+) -> TokenStream2 {
+    let vis = &input.vis;
+    let enum_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let len = variants.len();
+    quote_spanned!(span=>
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            /// Returns an iterator over all unit variants in declaration order.
+            #[allow(dead_code)]
+            #vis fn iter_variants() -> impl ::core::iter::Iterator<Item = Self> {
+                let __bf_variants: [Self; #len] = [ #( Self::#variants ),* ];
+                ::core::iter::IntoIterator::into_iter(__bf_variants)
+            }
+        }
+    )
+}
+
+/// Generates the compile-time checks that the discriminant of every unit variant is
+/// non-negative and fits into the bit width of the enum.
+fn generate_discriminant_checks(
+    input: &syn::ItemEnum,
+    variants: &[&syn::Ident],
+    patterns: &[TokenStream2],
+    bits: &TokenStream2,
+    bits_description: &str,
+) -> syn::Result<TokenStream2> {
+    let enum_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    // Discriminants may only be negative without a `#[repr(uN)]`.
+    let signed_repr =
+        parse_repr(&input.attrs)?.map_or(true, |(repr, _)| repr.to_string().starts_with('i'));
+    let descriptions = discriminant_descriptions(input);
+    let checks = variants.iter().zip(patterns).zip(&descriptions).map(
+        |((ident, pattern), description)| {
+            let span = ident.span();
+            let message = format!(
+                "variant `{ident}` discriminant {description} does not fit in {bits_description} bits"
+            );
+            let check_negative = signed_repr.then(|| {
+                let message = format!(
+                    "variant `{ident}` discriminant {description} is negative but \
+                     #[derive(Specifier)] stores discriminants as unsigned bits"
+                );
+                quote_spanned!(span=>
+                    ::core::assert!((#pattern as ::core::primitive::i128) >= 0, #message);
+                )
+            });
+            quote_spanned!(span=>
+                #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+                impl #impl_generics ::modular_bitfield::private::checks::CheckDiscriminantInRange<
+                    ::modular_bitfield::private::checks::BitCount<{#pattern as ::core::primitive::usize}>
+                > for #enum_ident #ty_generics #where_clause {
+                    type CheckType = ::modular_bitfield::private::checks::BitCount<{
+                        #check_negative
+                        ::core::assert!((#pattern as ::core::primitive::usize) < (1 << (#bits)), #message);
+                        1
+                    }>;
+                }
+            )
+        },
+    );
+    Ok(quote_spanned!(input.span()=> #( #checks )*))
+}
+
+/// Generates the `MIN` and `MAX` associated constants holding the unit variants with the
+/// smallest and largest discriminant respectively for `#[specifier(min_max)]`.
+fn generate_min_max(
+    span: proc_macro2::Span,
+    input: &syn::ItemEnum,
+    variants: &[&syn::Ident],
+    patterns: &[TokenStream2],
+) -> syn::Result<TokenStream2> {
+    let vis = &input.vis;
+    let enum_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let Some(first) = variants.first() else {
+        return Err(format_err!(
+            span,
+            "#[specifier(min_max)] requires at least one unit variant",
+        ));
+    };
+    let indices = (0..variants.len()).collect::<Vec<_>>();
+    let find_index = |cmp: TokenStream2| {
+        quote_spanned!(span=>
+            let __bf_discriminants = [ #( #patterns as ::core::primitive::i128 ),* ];
+            let mut __bf_found = 0;
+            let mut __bf_index = 1;
+            while __bf_index < __bf_discriminants.len() {
+                if __bf_discriminants[__bf_index] #cmp __bf_discriminants[__bf_found] {
+                    __bf_found = __bf_index;
+                }
+                __bf_index += 1;
+            }
+            match __bf_found {
+                #( #indices => Self::#variants, )*
+                _ => Self::#first,
+            }
+        )
+    };
+    let find_min = find_index(quote_spanned!(span=> <));
+    let find_max = find_index(quote_spanned!(span=> >));
+    Ok(quote_spanned!(span=>
+        #[allow(clippy::cast_lossless)]
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            /// The variant with the smallest discriminant.
+            #vis const MIN: Self = { #find_min };
+
+            /// The variant with the largest discriminant.
+            #vis const MAX: Self = { #find_max };
+        }
+    ))
+}
+
+/// Generates the `core::fmt::Binary` implementation requested by `#[specifier(binary)]`.
+///
+/// Formats the bits of a variant zero-padded to `BITS` digits and prefixed by `0b`
+/// if the alternate flag is set.
+fn generate_binary(
+    span: proc_macro2::Span,
+    input: &syn::ItemEnum,
+    catch_all: Option<&syn::Variant>,
+    variants: &[&syn::Ident],
+    patterns: &[TokenStream2],
+) -> TokenStream2 {
+    let enum_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let catch_all_arm = catch_all.map(|catch_all| {
+        let catch_all = &catch_all.ident;
+        quote_spanned!(span=>
+            Self::#catch_all(__bf_raw) => *__bf_raw,
+        )
+    });
+    quote_spanned!(span=>
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+        impl #impl_generics ::core::fmt::Binary for #enum_ident #ty_generics #where_clause {
+            fn fmt(&self, __bf_f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let __bf_bytes: <Self as ::modular_bitfield::Specifier>::Bytes = match self {
+                    #( Self::#variants => #patterns as <Self as ::modular_bitfield::Specifier>::Bytes, )*
+                    #catch_all_arm
+                };
+                if __bf_f.alternate() {
+                    __bf_f.write_str("0b")?;
+                }
+                ::core::write!(
+                    __bf_f,
+                    "{:0__bf_width$b}",
+                    __bf_bytes,
+                    __bf_width = <Self as ::modular_bitfield::Specifier>::BITS,
+                )
+            }
+        }
+    )
+}
+
+/// Generates the `FromStr` impl requested by `#[specifier(from_str)]` which matches
+/// the names of the unit variants case-sensitively.
+fn generate_from_str(
+    span: proc_macro2::Span,
+    input: &syn::ItemEnum,
+    variants: &[&syn::Ident],
+) -> TokenStream2 {
+    let enum_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let names = variants.iter().map(|ident| ident.unraw().to_string());
+    quote_spanned!(span=>
+        impl #impl_generics ::core::str::FromStr for #enum_ident #ty_generics #where_clause {
+            type Err = ::modular_bitfield::error::UnknownVariant;
+
+            #[inline]
+            fn from_str(__bf_name: &::core::primitive::str) -> ::core::result::Result<Self, Self::Err> {
+                match __bf_name {
+                    #( #names => ::core::result::Result::Ok(Self::#variants), )*
+                    _ => ::core::result::Result::Err(::modular_bitfield::error::UnknownVariant),
+                }
+            }
+        }
+    )
+}
+
+/// Generates the `From` conversions between a two variant enum and `bool` requested by
+/// `#[specifier(bool)]`.
+///
+/// The variant with the bit pattern `0` converts to `false` and the other one to `true`.
+fn generate_bool_conversions(
+    span: proc_macro2::Span,
+    input: &syn::ItemEnum,
+    catch_all: Option<&syn::Variant>,
+    variants: &[&syn::Ident],
+) -> syn::Result<TokenStream2> {
+    if catch_all.is_some() || variants.len() != 2 {
+        return Err(format_err!(
+            span,
+            "#[specifier(bool)] requires an enum with exactly two unit variants",
+        ));
+    }
+    let enum_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote_spanned!(span=>
+        const _: () = ::core::assert!(
+            <#enum_ident as ::modular_bitfield::Specifier>::BITS == 1,
+            "#[specifier(bool)] requires a 1 bit enum",
+        );
+
+        impl #impl_generics ::core::convert::From<#enum_ident #ty_generics> for ::core::primitive::bool #where_clause {
+            #[inline]
+            fn from(__bf_value: #enum_ident #ty_generics) -> Self {
+                <#enum_ident #ty_generics as ::modular_bitfield::Specifier>::into_bytes(__bf_value)
+                    == ::core::result::Result::Ok(1)
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<::core::primitive::bool> for #enum_ident #ty_generics #where_clause {
+            #[inline]
+            fn from(__bf_value: ::core::primitive::bool) -> Self {
+                // Both bit patterns of a 1 bit enum with two variants are valid.
+                match <Self as ::modular_bitfield::Specifier>::from_bytes(::core::convert::From::from(__bf_value)) {
+                    ::core::result::Result::Ok(__bf_variant) => __bf_variant,
+                    ::core::result::Result::Err(_) => ::core::unreachable!(),
+                }
+            }
+        }
+    ))
+}
+
+/// Generates the `unsafe` `from_bytes_unchecked` constructor requested by
+/// `#[specifier(unchecked)]`.
+///
+/// Invalid bit patterns are declared unreachable which allows the compiler to elide
+/// the checks of `Specifier::from_bytes` without relying on the layout of the enum.
+fn generate_from_bytes_unchecked(
+    span: proc_macro2::Span,
+    input: &syn::ItemEnum,
+    catch_all: Option<&syn::Variant>,
+    variants: &[&syn::Ident],
+    patterns: &[TokenStream2],
+) -> syn::Result<TokenStream2> {
+    if let Some(catch_all) = catch_all {
+        return Err(format_err!(
+            catch_all.ident,
+            "#[specifier(unchecked)] is not supported with a 'catch_all' variant \
+             since its conversion from bits never fails",
+        ));
+    }
+    let vis = &input.vis;
+    let enum_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote_spanned!(span=>
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            /// Converts the given bits into the variant with the same bit pattern without
+            /// checking that the bits are valid.
+            ///
+            /// # Safety
+            ///
+            /// The bits must be the bit pattern of a variant, i.e.
+            /// `Specifier::from_bytes(bytes)` must return `Ok`.
+            /// Otherwise the behavior is undefined.
+            #[inline]
+            #[must_use]
+            #vis unsafe fn from_bytes_unchecked(
+                bytes: <Self as ::modular_bitfield::Specifier>::Bytes,
+            ) -> Self {
+                match bytes {
+                    #(
+                        __bf_binding if __bf_binding == #patterns as <Self as ::modular_bitfield::Specifier>::Bytes => {
+                            Self::#variants
+                        }
+                    )*
+                    // SAFETY: The caller guarantees that the bits are a valid pattern.
+                    _ => unsafe { ::core::hint::unreachable_unchecked() },
+                }
+            }
+        }
+    ))
+}
+
+/// Returns the bit width of the enum as well as its description for error messages.
+///
+/// Without a `#[bits = N]` attribute the bit width is derived from the number of variants.
+fn enum_bits(
+    input: &syn::ItemEnum,
+    bits: Option<syn::Expr>,
+) -> syn::Result<(TokenStream2, String)> {
+    let span = input.span();
+    match bits {
+        Some(bits @ syn::Expr::Lit(_)) => {
+            let description = bits.to_token_stream().to_string();
+            return Ok((quote_spanned!(bits.span()=> #bits), description));
+        }
+        Some(bits) => {
+            let description = format!("`{}`", bits.to_token_stream());
+            return Ok((quote_spanned!(bits.span()=> { #bits }), description));
+        }
+        None => {}
+    }
+    let count_variants = input.variants.iter().count();
+    if !count_variants.is_power_of_two() {
+        return Err(format_err!(
+            span,
+            "#[derive(Specifier)] expected a number of variants which is a power of 2, specify #[bits = {}] if that was your intent",
+            count_variants.next_power_of_two().trailing_zeros(),
+        ));
+    }
+    // We can take `trailing_zeros` returns type as the required amount of bits.
+    let Some(power_of_two) = count_variants.checked_next_power_of_two() else {
+        return Err(format_err!(
+            span,
+            "#[derive(Specifier)] has too many variants to pack into a bitfield",
+        ));
+    };
+    let bits = power_of_two.trailing_zeros() as usize;
+    Ok((quote_spanned!(span=> #bits), bits.to_string()))
+}
+
+/// Generates the body of `into_bytes` and the fallback arm of `from_bytes` for unmatched
+/// bit patterns, which are stored in the `#[catch_all]` variant if any.
+fn generate_conversion_bodies(
+    span: proc_macro2::Span,
+    catch_all: Option<&syn::Variant>,
+    variants: &[&syn::Ident],
+    patterns: &[TokenStream2],
+) -> (TokenStream2, TokenStream2) {
+    match catch_all {
+        Some(catch_all) => {
+            let catch_all = &catch_all.ident;
+            let into_bytes_arms = variants.iter().zip(patterns).map(|(ident, pattern)| {
+                let span = ident.span();
+                quote_spanned!(span=>
+                    Self::#ident => ::core::result::Result::Ok(#pattern as <Self as ::modular_bitfield::Specifier>::Bytes),
+                )
+            });
+            // The raw bits of the `#[catch_all]` variant must not alias a unit variant
+            // since they would be read back as that variant.
+            let alias_arms = variants.iter().zip(patterns).map(|(ident, pattern)| {
+                let span = ident.span();
+                quote_spanned!(span=>
+                    __bf_binding if __bf_binding == #pattern as <Self as ::modular_bitfield::Specifier>::Bytes => {
+                        ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                    }
+                )
+            });
+            (
+                quote_spanned!(span=>
+                    match input {
+                        #( #into_bytes_arms )*
+                        Self::#catch_all(__bf_raw) => {
+                            // Truncation of BITS is always valid due to maximum of 128
+                            #[allow(clippy::cast_possible_truncation)]
+                            let __bf_unused_bits = ::core::primitive::u128::from(__bf_raw)
+                                .checked_shr(<Self as ::modular_bitfield::Specifier>::BITS as ::core::primitive::u32)
+                                .unwrap_or(0);
+                            if __bf_unused_bits != 0 {
+                                return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds);
+                            }
+                            match __bf_raw {
+                                #( #alias_arms )*
+                                _ => ::core::result::Result::Ok(__bf_raw),
+                            }
+                        }
+                    }
+                ),
+                quote_spanned!(span=>
+                    __bf_raw => {
+                        // Truncation of BITS is always valid due to maximum of 128
+                        #[allow(clippy::cast_possible_truncation)]
+                        let __bf_unused_bits = ::core::primitive::u128::from(__bf_raw)
+                            .checked_shr(<Self as ::modular_bitfield::Specifier>::BITS as ::core::primitive::u32)
+                            .unwrap_or(0);
+                        if __bf_unused_bits != 0 {
+                            return ::core::result::Result::Err(
+                                <::modular_bitfield::error::InvalidBitPattern<<Self as ::modular_bitfield::Specifier>::Bytes>>::new(__bf_raw)
+                            );
+                        }
+                        ::core::result::Result::Ok(Self::#catch_all(__bf_raw))
+                    }
+                ),
+            )
+        }
+        None => (
+            quote_spanned!(span=>
+                ::core::result::Result::Ok(input as <Self as ::modular_bitfield::Specifier>::Bytes)
+            ),
+            quote_spanned!(span=>
+                invalid_bytes => {
+                    ::core::result::Result::Err(
+                        <::modular_bitfield::error::InvalidBitPattern<<Self as ::modular_bitfield::Specifier>::Bytes>>::new(invalid_bytes)
+                    )
+                }
+            ),
+        ),
+    }
+}
+
+/// Returns the variant annotated with `#[catch_all]` if any.
+fn find_catch_all(input: &syn::ItemEnum) -> syn::Result<Option<&syn::Variant>> {
+    let mut catch_all = None;
+    for variant in &input.variants {
+        for attr in variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("catch_all"))
+        {
+            attr.meta.require_path_only()?;
+            if catch_all.is_some() {
+                return Err(format_err_spanned!(
+                    attr,
+                    "More than one 'catch_all' variant is not permitted",
+                ));
+            }
+            if !matches!(&variant.fields, syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1)
+            {
+                return Err(format_err_spanned!(
+                    variant,
+                    "'catch_all' variant must have exactly one unnamed field holding the raw bits",
+                ));
+            }
+            catch_all = Some(variant);
+        }
+    }
+    Ok(catch_all)
+}
+
+/// Returns the bit patterns of the unit variants of an enum with a `#[catch_all]` variant.
+///
+/// Enums with data variants cannot be cast via `as` so the patterns are derived from the
+/// discriminants the same way as Rust does: explicit discriminants are used as is while
+/// all other variants count up by one from the previous variant, starting at `0`.
+fn discriminant_patterns(input: &syn::ItemEnum) -> syn::Result<Vec<TokenStream2>> {
+    // Explicit discriminants are of the `#[repr(..)]` type and `isize` by default.
+    let repr = parse_repr(&input.attrs)?
+        .map_or_else(|| syn::Ident::new("isize", input.span()), |(repr, _)| repr);
+    let mut base = None;
+    let mut offset = 0_usize;
+    let mut patterns = Vec::new();
+    for variant in &input.variants {
+        match &variant.fields {
+            syn::Fields::Unit => {}
+            _ if variant
+                .attrs
+                .iter()
+                .any(|attr| attr.path().is_ident("catch_all")) =>
+            {
+                // The `catch_all` variant has no bit pattern but still occupies a discriminant.
+                if variant.discriminant.is_some() {
+                    return Err(format_err_spanned!(
+                        variant,
+                        "'catch_all' variant must not have an explicit discriminant",
+                    ));
+                }
+                offset += 1;
+                continue;
+            }
+            _ => {
+                return Err(format_err_spanned!(
+                    variant,
+                    "only unit variants are permitted besides the 'catch_all' variant",
+                ))
+            }
+        }
+        let span = variant.span();
+        if let Some((_, discriminant)) = &variant.discriminant {
+            base = Some(quote_spanned!(discriminant.span()=>
+                { let __bf_discriminant: ::core::primitive::#repr = #discriminant; __bf_discriminant }
+            ));
+            offset = 0;
+        }
+        let offset_lit = syn::LitInt::new(&offset.to_string(), span);
+        let offset_isize = syn::LitInt::new(&format!("{offset}_isize"), span);
+        let pattern = match &base {
+            Some(base) if offset == 0 => quote_spanned!(span=> (#base)),
+            Some(base) => quote_spanned!(span=> (#base + #offset_lit)),
+            None => quote_spanned!(span=> #offset_isize),
+        };
+        patterns.push(pattern);
+        offset += 1;
+    }
+    Ok(patterns)
+}
+
+/// Returns a human readable description of the discriminant of every unit variant for
+/// compile-time error messages.
+///
+/// Discriminants are computed if they are derived from integer literals only and are
+/// otherwise described in terms of the last explicit discriminant expression.
+fn discriminant_descriptions(input: &syn::ItemEnum) -> Vec<String> {
+    let mut base: Option<Result<i128, String>> = None;
+    let mut offset = 0_i128;
+    let mut descriptions = Vec::new();
+    for variant in &input.variants {
+        if let Some((_, discriminant)) = &variant.discriminant {
+            base = Some(
+                integer_literal_value(discriminant)
+                    .ok_or_else(|| discriminant.to_token_stream().to_string()),
+            );
+            offset = 0;
+        }
+        if matches!(variant.fields, syn::Fields::Unit) {
+            descriptions.push(match &base {
+                None => offset.to_string(),
+                Some(Ok(base)) => (base + offset).to_string(),
+                Some(Err(base)) if offset == 0 => format!("`{base}`"),
+                Some(Err(base)) => format!("`{base} + {offset}`"),
+            });
+        }
+        offset += 1;
+    }
+    descriptions
+}
+
+/// Returns the value of a possibly negated integer literal expression.
+fn integer_literal_value(expr: &syn::Expr) -> Option<i128> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => lit.base10_parse().ok(),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => integer_literal_value(expr).map(|value: i128| -value),
+        syn::Expr::Paren(syn::ExprParen { expr, .. }) => integer_literal_value(expr),
+        _ => None,
+    }
+}
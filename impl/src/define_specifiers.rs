@@ -3,20 +3,27 @@ use quote::{format_ident, quote};
 
 pub fn generate(_input: TokenStream2) -> TokenStream2 {
     let specifiers = (1..=128).map(generate_specifier_for);
+    let bool_array_specifiers = (1..=128).map(generate_bool_array_specifier_for);
     quote! {
         #( #specifiers )*
+        #( #bool_array_specifiers )*
     }
 }
 
-fn generate_specifier_for(bits: usize) -> TokenStream2 {
-    let in_out = match bits {
+/// Returns the smallest unsigned integer primitive that can hold the given amount of bits.
+fn bytes_type_for(bits: usize) -> TokenStream2 {
+    match bits {
         1..=8 => quote! { ::core::primitive::u8 },
         9..=16 => quote! { ::core::primitive::u16 },
         17..=32 => quote! { ::core::primitive::u32 },
         33..=64 => quote! { ::core::primitive::u64 },
         65..=128 => quote! { ::core::primitive::u128 },
         _ => unreachable!(),
-    };
+    }
+}
+
+fn generate_specifier_for(bits: usize) -> TokenStream2 {
+    let in_out = bytes_type_for(bits);
     let ident = format_ident!("B{bits}");
     let doc_comment = if bits == 1 {
         "Specifier for a single bit.".to_string()
@@ -65,3 +72,36 @@ fn generate_specifier_for(bits: usize) -> TokenStream2 {
         impl crate::private::checks::private::Sealed for crate::private::checks::BitCount<#bits> {}
     }
 }
+
+/// Generates the specifier for `[bool; N]` packing the `N` booleans into contiguous bits.
+///
+/// The boolean at index `i` is stored in the `i`-th least significant bit.
+fn generate_bool_array_specifier_for(bits: usize) -> TokenStream2 {
+    let bytes = bytes_type_for(bits);
+    let unused_bits = bits.next_power_of_two().max(8) - bits;
+    let unused_bits = u32::try_from(unused_bits).expect("unused bits fit into u32");
+    quote! {
+        impl crate::Specifier for [::core::primitive::bool; #bits] {
+            const BITS: usize = #bits;
+            type Bytes = #bytes;
+            type InOut = [::core::primitive::bool; #bits];
+
+            #[inline]
+            fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, crate::OutOfBounds> {
+                let mut bytes: #bytes = 0;
+                for (index, bit) in input.into_iter().enumerate() {
+                    bytes |= <#bytes>::from(bit) << index;
+                }
+                Ok(bytes)
+            }
+
+            #[inline]
+            fn from_bytes(bytes: Self::Bytes) -> Result<Self::InOut, crate::InvalidBitPattern<Self::Bytes>> {
+                if bytes.leading_zeros() < #unused_bits {
+                    return Err(crate::InvalidBitPattern::new(bytes));
+                }
+                Ok(::core::array::from_fn(|index| bytes & (1 << index) != 0))
+            }
+        }
+    }
+}
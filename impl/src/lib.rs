@@ -23,7 +23,7 @@ pub fn bitfield(args: TokenStream, input: TokenStream) -> TokenStream {
     bitfield::analyse_and_expand(args.into(), input.into()).into()
 }
 
-#[proc_macro_derive(Specifier, attributes(bits))]
+#[proc_macro_derive(Specifier, attributes(bits, catch_all, specifier))]
 pub fn specifier(input: TokenStream) -> TokenStream {
     bitfield_specifier::generate(input.into()).into()
 }
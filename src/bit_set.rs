@@ -0,0 +1,119 @@
+use crate::{
+    error::{InvalidBitPattern, OutOfBounds},
+    Specifier,
+};
+
+/// A specifier for a bank of `N` flags packed into `N` contiguous bits.
+///
+/// The flag at index `i` is stored in the `i`-th least significant bit.
+/// `N` must be within `1..=128`.
+///
+/// # Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// use modular_bitfield::BitSet;
+///
+/// #[bitfield]
+/// pub struct Interrupts {
+///     flags: BitSet<12>,
+///     priority: B4,
+/// }
+///
+/// let mut interrupts = Interrupts::new();
+/// let mut flags = interrupts.flags();
+/// flags.set(3, true);
+/// flags.set(11, true);
+/// interrupts.set_flags(flags);
+/// assert!(interrupts.flags().get(3));
+/// assert!(!interrupts.flags().get(4));
+/// assert_eq!(interrupts.into_bytes(), [0b0000_1000, 0b0000_1000]);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitSet<const N: usize> {
+    flags: [bool; N],
+}
+
+impl<const N: usize> BitSet<N> {
+    /// Returns a bit set with all flags cleared.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { flags: [false; N] }
+    }
+
+    /// Returns the flag at the given index.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is not less than `N`.
+    #[inline]
+    #[must_use]
+    pub const fn get(&self, index: usize) -> bool {
+        self.flags[index]
+    }
+
+    /// Sets the flag at the given index to `value`.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is not less than `N`.
+    #[inline]
+    pub fn set(&mut self, index: usize, value: bool) {
+        self.flags[index] = value;
+    }
+
+    /// Returns the number of set flags.
+    #[inline]
+    #[must_use]
+    pub fn count_ones(&self) -> usize {
+        self.flags.iter().filter(|flag| **flag).count()
+    }
+
+    /// Returns the flags as array of booleans.
+    #[inline]
+    #[must_use]
+    pub const fn into_array(self) -> [bool; N] {
+        self.flags
+    }
+}
+
+impl<const N: usize> Default for BitSet<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> From<[bool; N]> for BitSet<N> {
+    #[inline]
+    fn from(flags: [bool; N]) -> Self {
+        Self { flags }
+    }
+}
+
+impl<const N: usize> From<BitSet<N>> for [bool; N] {
+    #[inline]
+    fn from(bit_set: BitSet<N>) -> Self {
+        bit_set.flags
+    }
+}
+
+impl<const N: usize> Specifier for BitSet<N>
+where
+    [bool; N]: Specifier<InOut = [bool; N]>,
+{
+    const BITS: usize = <[bool; N] as Specifier>::BITS;
+    type Bytes = <[bool; N] as Specifier>::Bytes;
+    type InOut = Self;
+
+    #[inline]
+    fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
+        <[bool; N] as Specifier>::into_bytes(input.flags)
+    }
+
+    #[inline]
+    fn from_bytes(bytes: Self::Bytes) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>> {
+        <[bool; N] as Specifier>::from_bytes(bytes).map(Self::from)
+    }
+}
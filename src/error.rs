@@ -6,12 +6,28 @@ use core::fmt::Debug;
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct OutOfBounds;
 
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfBounds {}
+
 impl core::fmt::Display for OutOfBounds {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "encountered an out of bounds value")
     }
 }
 
+/// The given name did not match any variant of a `Specifier` enum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnknownVariant;
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownVariant {}
+
+impl core::fmt::Display for UnknownVariant {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "encountered an unknown variant name")
+    }
+}
+
 /// The bitfield contained an invalid bit pattern.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct InvalidBitPattern<Bytes> {
@@ -32,6 +48,9 @@ where
     }
 }
 
+#[cfg(feature = "std")]
+impl<Bytes> std::error::Error for InvalidBitPattern<Bytes> where Bytes: Debug {}
+
 impl<Bytes> InvalidBitPattern<Bytes> {
     /// Creates a new invalid bit pattern error.
     #[inline]
@@ -45,3 +64,41 @@ impl<Bytes> InvalidBitPattern<Bytes> {
         self.invalid_bytes
     }
 }
+
+/// A field of the bitfield contained an invalid bit pattern.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FieldError {
+    /// The name of the invalid field.
+    field: &'static str,
+}
+
+impl core::fmt::Display for FieldError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "encountered an invalid bit pattern in field `{}`",
+            self.field
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FieldError {}
+
+impl FieldError {
+    /// Creates a new field error for the field with the given name.
+    #[inline]
+    #[must_use]
+    pub fn new(field: &'static str) -> Self {
+        Self { field }
+    }
+
+    /// Returns the name of the invalid field.
+    ///
+    /// Fields of tuple structs are named by their index.
+    #[inline]
+    #[must_use]
+    pub fn field(self) -> &'static str {
+        self.field
+    }
+}
@@ -3,11 +3,17 @@
 #![forbid(unsafe_code)]
 #![warn(clippy::pedantic, missing_docs, rust_2018_idioms)]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+mod bit_set;
 pub mod error;
+mod masked;
 #[doc(hidden)]
 pub mod private;
 
 use self::error::{InvalidBitPattern, OutOfBounds};
+pub use self::{bit_set::BitSet, masked::Masked};
 
 #[doc = include_str!("../docs/bitfield.md")]
 pub use modular_bitfield_impl::bitfield;
@@ -70,6 +76,125 @@ pub trait Specifier {
     fn from_bytes(bytes: Self::Bytes) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>>;
 }
 
+/// Trait implemented by `#[bitfield]` structs for each of their fields, keyed by a
+/// marker type `K` that is generated for the field.
+///
+/// For a `#[bitfield]` struct `Packet` with a field `src_port` the marker type is
+/// `packet_fields::SrcPort`, and `packet_fields::Field0` for the first field of a tuple
+/// struct. This allows generic code to address fields of different bitfield structs.
+///
+/// Only implemented with the `field_trait` parameter of `#[bitfield]` and for fields that
+/// have both getters and setters.
+///
+/// # Example
+///
+/// ```
+/// use modular_bitfield::{prelude::*, Field};
+///
+/// #[bitfield(field_trait)]
+/// pub struct Packet {
+///     src_port: B4,
+///     dst_port: B4,
+/// }
+///
+/// fn swap<S, A, B, V>(s: &mut S)
+/// where
+///     S: Field<A, Value = V> + Field<B, Value = V>,
+/// {
+///     let a = <S as Field<A>>::read(s);
+///     let b = <S as Field<B>>::read(s);
+///     <S as Field<A>>::write(s, b);
+///     <S as Field<B>>::write(s, a);
+/// }
+///
+/// let mut packet = Packet::new().with_src_port(1).with_dst_port(2);
+/// swap::<_, packet_fields::SrcPort, packet_fields::DstPort, _>(&mut packet);
+/// assert_eq!(packet.src_port(), 2);
+/// assert_eq!(packet.dst_port(), 1);
+/// ```
+pub trait Field<K> {
+    /// The interface type of the field.
+    type Value;
+
+    /// Returns the value of the field.
+    ///
+    /// # Panics
+    ///
+    /// If the field contains an invalid bit pattern.
+    fn read(&self) -> Self::Value;
+
+    /// Sets the value of the field.
+    ///
+    /// # Panics
+    ///
+    /// If the value is out of bounds for the field.
+    fn write(&mut self, value: Self::Value);
+}
+
+/// Asserts that the fields of a `#[bitfield]` struct occupy the given bit ranges.
+///
+/// This allows pinning the layout of a bitfield struct in a test suite so that
+/// accidental layout changes, e.g. by reordering or resizing fields, are caught.
+/// Fields of tuple structs are addressed by their index. Fields that are not listed
+/// are not checked. Requires the `field_layout` parameter on the bitfield struct.
+///
+/// # Panics
+///
+/// If any of the given fields does not occupy exactly the given bit range.
+///
+/// # Example
+///
+/// ```
+/// use modular_bitfield::{assert_field_layout, prelude::*};
+///
+/// #[bitfield(field_layout)]
+/// pub struct Packet {
+///     header: B4,
+///     is_alive: bool,
+///     status: B3,
+///     body: B8,
+/// }
+///
+/// assert_field_layout!(Packet,
+///     header => 0..4,
+///     is_alive => 4..5,
+///     status => 5..8,
+///     body => 8..16,
+/// );
+///
+/// #[bitfield(field_layout)]
+/// pub struct Pair(B6, B2);
+///
+/// assert_field_layout!(Pair, 0 => 0..6, 1 => 6..8);
+/// ```
+///
+/// A mismatch panics with the name of the field:
+///
+/// ```should_panic
+/// # use modular_bitfield::{assert_field_layout, prelude::*};
+/// #
+/// #[bitfield(field_layout)]
+/// pub struct Packet {
+///     header: B4,
+///     body: B4,
+/// }
+///
+/// assert_field_layout!(Packet, body => 0..4);
+/// ```
+#[macro_export]
+macro_rules! assert_field_layout {
+    ($ty:ty, $( $field:tt => $range:expr ),+ $(,)?) => {{
+        let __bf_layout = <$ty>::__BF_FIELD_LAYOUT;
+        $(
+            ::core::assert_eq!(
+                __bf_layout.$field,
+                $range,
+                ::core::concat!("unexpected bit range of field `", ::core::stringify!($field), "`"),
+            );
+        )+
+    }};
+}
+
 /// The default set of predefined specifiers.
 pub mod specifiers {
     ::modular_bitfield_impl::define_specifiers!();
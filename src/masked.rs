@@ -0,0 +1,67 @@
+use crate::{
+    error::{InvalidBitPattern, OutOfBounds},
+    Specifier,
+};
+use core::marker::PhantomData;
+
+/// A specifier adapter that only allows the bits of `MASK` to be set in the
+/// inner specifier `T`.
+///
+/// This is useful for fields of hardware registers that have reserved bits
+/// which must always be zero. Setting a value with bits outside of `MASK`
+/// fails with [`OutOfBounds`] and reading a bit pattern with bits outside of
+/// `MASK` fails with [`InvalidBitPattern`].
+///
+/// # Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// use modular_bitfield::Masked;
+///
+/// #[bitfield]
+/// pub struct Control {
+///     mode: Masked<B8, 0b1111_0000>,
+///     rest: B8,
+/// }
+///
+/// let mut control = Control::new();
+/// control.set_mode(0b1010_0000);
+/// assert_eq!(control.mode(), 0b1010_0000);
+/// assert!(control.set_mode_checked(0b0000_0001).is_err());
+///
+/// let control = Control::from_bytes([0b0000_0001, 0]);
+/// assert!(control.mode_or_err().is_err());
+/// ```
+#[derive(Copy, Clone)]
+pub struct Masked<T, const MASK: u128> {
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T, const MASK: u128> Specifier for Masked<T, MASK>
+where
+    T: Specifier,
+    T::Bytes: Copy + Into<u128>,
+{
+    const BITS: usize = T::BITS;
+    type Bytes = T::Bytes;
+    type InOut = T::InOut;
+
+    #[inline]
+    fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
+        let bytes = T::into_bytes(input)?;
+        if bytes.into() & !MASK == 0 {
+            Ok(bytes)
+        } else {
+            Err(OutOfBounds)
+        }
+    }
+
+    #[inline]
+    fn from_bytes(bytes: Self::Bytes) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>> {
+        if bytes.into() & !MASK == 0 {
+            T::from_bytes(bytes)
+        } else {
+            Err(InvalidBitPattern::new(bytes))
+        }
+    }
+}
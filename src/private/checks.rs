@@ -1,3 +1,5 @@
+use crate::Specifier;
+
 pub(crate) mod private {
     /// Prevents internal traits from being implemented from dependencies.
     pub trait Sealed {}
@@ -84,6 +86,10 @@ where
 /// is within valid bounds.
 pub trait DiscriminantInRange: private::Sealed {}
 
+/// Helper trait to check if a field of a bitfield struct starts and
+/// ends at byte boundaries.
+pub trait FieldIsByteAligned: private::Sealed {}
+
 /// Helper trait to check if a `#[derive(Specifier)]` flagged bitfield
 /// requires
 /// at most 128 bits.
@@ -106,6 +112,7 @@ pub enum False {}
 impl private::Sealed for True {}
 impl DiscriminantInRange for True {}
 impl SpecifierHasAtMost128Bits for True {}
+impl FieldIsByteAligned for True {}
 impl FillsUnalignedBits for True {}
 impl DoesNotFillUnalignedBits for True {}
 
@@ -117,6 +124,37 @@ pub trait DispatchTrueFalse: private::Sealed {
 /// Helper type for compile time evaluation of the number of bits.
 pub struct BitCount<const N: usize>;
 
+/// Helper type to check if two specifiers have the same bit width.
+pub struct SameBitWidth<A, B>(::core::marker::PhantomData<(A, B)>);
+
+impl<A, B> SameBitWidth<A, B>
+where
+    A: Specifier,
+    B: Specifier,
+{
+    /// Fails to evaluate if the bit widths of `A` and `B` differ.
+    pub const ASSERT: () = assert!(
+        A::BITS == B::BITS,
+        "encountered specifiers with mismatching bit widths"
+    );
+}
+
+/// Helper type to warn about bitfields with undefined bits padding them to full bytes.
+///
+/// Used by the `#[bitfield(warn_waste)]` parameter.
+pub struct WasteCheck<const WASTEFUL: bool>;
+
+impl WasteCheck<true> {
+    /// Triggers a deprecation warning when used.
+    #[deprecated(note = "bitfield struct has undefined bits padding it to full bytes")]
+    pub const CHECK: () = ();
+}
+
+impl WasteCheck<false> {
+    /// Does not trigger a warning when used.
+    pub const CHECK: () = ();
+}
+
 impl private::Sealed for BitCount<0> {}
 impl DispatchTrueFalse for BitCount<0> {
     type Out = False;
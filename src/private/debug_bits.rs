@@ -0,0 +1,32 @@
+use core::fmt;
+
+/// Formats a byte slice as a list of hexadecimal bytes, e.g. `[0x12, 0xAB]`.
+pub struct DebugBits<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> DebugBits<'a> {
+    /// Creates a new hexadecimal formatter for the given bytes.
+    #[inline]
+    #[must_use]
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+}
+
+impl fmt::Debug for DebugBits<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.bytes.iter().copied().map(HexByte))
+            .finish()
+    }
+}
+
+/// A single byte that is always formatted as `0xNN`.
+struct HexByte(u8);
+
+impl fmt::Debug for HexByte {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#04X}", self.0)
+    }
+}
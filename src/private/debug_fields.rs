@@ -0,0 +1,30 @@
+use core::fmt;
+
+/// Implemented by `#[bitfield]` structs to format their fields on demand.
+pub trait DebugFields {
+    /// Formats the fields of the bitfield like its `#[derive(Debug)]` impl would.
+    fn fmt_fields(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// Decodes and formats the fields of a bitfield only when it is actually formatted.
+pub struct FieldsDebug<'a, T> {
+    bitfield: &'a T,
+}
+
+impl<'a, T> FieldsDebug<'a, T> {
+    /// Creates a new lazy field formatter for the given bitfield.
+    #[inline]
+    #[must_use]
+    pub fn new(bitfield: &'a T) -> Self {
+        Self { bitfield }
+    }
+}
+
+impl<T> fmt::Debug for FieldsDebug<'_, T>
+where
+    T: DebugFields,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.bitfield.fmt_fields(f)
+    }
+}
@@ -51,3 +51,31 @@ impl_specifier_for_primitive!(
     (u64: 64),
     (u128: 128),
 );
+
+#[cfg(feature = "std")]
+macro_rules! impl_specifier_for_ip_addr {
+    ( $( ($addr:ty: $prim:ty, $bits:literal) ),* $(,)? ) => {
+        $(
+            impl Specifier for $addr {
+                const BITS: usize = $bits;
+                type Bytes = $prim;
+                type InOut = $addr;
+
+                #[inline]
+                fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
+                    Ok(<$prim>::from(input))
+                }
+
+                #[inline]
+                fn from_bytes(bytes: Self::Bytes) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>> {
+                    Ok(<$addr>::from(bytes))
+                }
+            }
+        )*
+    };
+}
+#[cfg(feature = "std")]
+impl_specifier_for_ip_addr!(
+    (std::net::Ipv4Addr: u32, 32),
+    (std::net::Ipv6Addr: u128, 128),
+);
@@ -0,0 +1,18 @@
+use crate::error::OutOfBounds;
+use std::io::{Error, ErrorKind};
+pub use std::io::{Read, Result, Write};
+
+/// Reads exactly `N` bytes from the given reader.
+#[inline]
+pub fn read_bytes<const N: usize>(reader: &mut impl Read) -> Result<[u8; N]> {
+    let mut bytes = [0x00; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Converts bytes that are invalid for a bitfield into an I/O error.
+#[inline]
+#[must_use]
+pub fn invalid_data(error: OutOfBounds) -> Error {
+    Error::new(ErrorKind::InvalidData, error)
+}
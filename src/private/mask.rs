@@ -0,0 +1,23 @@
+/// Returns the mask of the `bits` least significant bits of a byte array of length `N`.
+///
+/// Used to determine the defined bits of `#[bitfield]` structs with undefined bits.
+#[doc(hidden)]
+#[inline]
+#[must_use]
+pub const fn defined_bits_mask<const N: usize>(bits: usize) -> [u8; N] {
+    let mut mask = [0x00_u8; N];
+    let mut index = 0;
+    while index < N {
+        let defined = bits.saturating_sub(index * 8);
+        mask[index] = if defined >= 8 {
+            0xFF
+        } else {
+            // Truncation is always valid since `defined` is less than 8.
+            #[allow(clippy::cast_possible_truncation)]
+            let shifted = (1_u16 << defined) as u8;
+            shifted.wrapping_sub(1)
+        };
+        index += 1;
+    }
+    mask
+}
@@ -1,6 +1,11 @@
 mod array_bytes_conv;
 pub mod checks;
+mod debug_bits;
+mod debug_fields;
 mod impls;
+#[cfg(feature = "std")]
+pub mod io;
+mod mask;
 mod proc;
 mod push_pop;
 mod traits;
@@ -10,10 +15,16 @@ pub mod static_assertions {
 }
 pub use self::{
     array_bytes_conv::ArrayBytesConversion,
-    proc::{read_specifier, write_specifier},
+    debug_bits::DebugBits,
+    debug_fields::{DebugFields, FieldsDebug},
+    mask::defined_bits_mask,
+    proc::{
+        fits_bits, read_bits, read_specifier, reverse_bits, write_bits, write_bits_from_iter,
+        write_specifier,
+    },
     push_pop::{PopBuffer, PushBuffer},
     traits::{
         IsU128Compatible, IsU16Compatible, IsU32Compatible, IsU64Compatible, IsU8Compatible,
-        PopBits, PushBits, SpecifierBytes,
+        PopBits, PushBits, ReverseBits, SpecifierBytes,
     },
 };
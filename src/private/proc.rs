@@ -1,5 +1,5 @@
 use crate::{
-    private::{PopBits, PopBuffer, PushBits, PushBuffer},
+    private::{PopBits, PopBuffer, PushBits, PushBuffer, ReverseBits},
     Specifier,
 };
 
@@ -62,6 +62,18 @@ where
     buffer.into_bytes()
 }
 
+/// Reverses the order of the `T::BITS` bits of the given raw value of `T`.
+#[doc(hidden)]
+#[inline]
+#[must_use]
+pub fn reverse_bits<T>(bytes: <T as Specifier>::Bytes) -> <T as Specifier>::Bytes
+where
+    T: Specifier,
+    T::Bytes: ReverseBits,
+{
+    bytes.reverse_low_bits(<T as Specifier>::BITS)
+}
+
 #[doc(hidden)]
 #[inline]
 pub fn write_specifier<T>(bytes: &mut [u8], offset: usize, new_val: <T as Specifier>::Bytes)
@@ -117,3 +129,84 @@ where
         }
     }
 }
+
+/// Reads `bits` bits starting at bit `offset` from `bytes` in a `const` context.
+///
+/// Used by the getters of primitive fields which do not need to go through
+/// the non-`const` [`Specifier::from_bytes`].
+#[doc(hidden)]
+#[inline]
+#[must_use]
+pub const fn read_bits(bytes: &[u8], offset: usize, bits: usize) -> u128 {
+    let ls_byte = offset / 8;
+    let ms_byte = (offset + bits - 1) / 8;
+    let lsb_offset = offset % 8;
+    let mut value = (bytes[ls_byte] as u128) >> lsb_offset;
+    let mut index = ls_byte + 1;
+    while index <= ms_byte {
+        value |= (bytes[index] as u128) << ((index - ls_byte) * 8 - lsb_offset);
+        index += 1;
+    }
+    if bits < 128 {
+        value &= (1 << bits) - 1;
+    }
+    value
+}
+
+/// Returns `true` if `value` can be stored in `bits` bits.
+#[doc(hidden)]
+#[inline]
+#[must_use]
+pub const fn fits_bits(value: u128, bits: usize) -> bool {
+    // Truncation is always valid since a specifier has at most 128 bits.
+    #[allow(clippy::cast_possible_truncation)]
+    let bits = bits as u32;
+    matches!(value.checked_shr(bits), None | Some(0))
+}
+
+/// Writes the lower `bits` bits of `value` starting at bit `offset` into `bytes` in a
+/// `const` context and returns the updated bytes.
+///
+/// Used by `from_fields` of bitfields with only primitive fields which do not need to
+/// go through the non-`const` [`Specifier::into_bytes`].
+#[doc(hidden)]
+#[inline]
+#[must_use]
+pub const fn write_bits<const N: usize>(
+    mut bytes: [u8; N],
+    offset: usize,
+    bits: usize,
+    value: u128,
+) -> [u8; N] {
+    let mut index = 0;
+    while index < bits {
+        let bit = offset + index;
+        if (value >> index) & 1 == 1 {
+            bytes[bit / 8] |= 1 << (bit % 8);
+        } else {
+            bytes[bit / 8] &= !(1 << (bit % 8));
+        }
+        index += 1;
+    }
+    bytes
+}
+
+/// Sets the bits of `bytes` LSB-first from the booleans yielded by `iter`.
+///
+/// Returns `false` if `iter` yields more than `bits` items.
+#[doc(hidden)]
+#[inline]
+pub fn write_bits_from_iter<I>(bytes: &mut [u8], bits: usize, iter: I) -> bool
+where
+    I: IntoIterator<Item = bool>,
+{
+    for (index, bit) in iter.into_iter().enumerate() {
+        if index >= bits {
+            return false;
+        }
+        if bit {
+            bytes[index / 8] |= 1 << (index % 8);
+        }
+    }
+    true
+}
@@ -27,6 +27,32 @@ pub trait SpecifierBytes: checks::private::Sealed {
     type Bytes;
 }
 
+/// Trait implemented by primitives that can reverse the order of their lowest bits.
+#[doc(hidden)]
+pub trait ReverseBits: checks::private::Sealed {
+    /// Reverses the order of the lowest `amount` bits, which must be at least one.
+    #[must_use]
+    fn reverse_low_bits(self, amount: usize) -> Self;
+}
+
+macro_rules! impl_reverse_bits {
+    ( $($type:ty),+ ) => {
+        $(
+            impl ReverseBits for $type {
+                #[inline]
+                fn reverse_low_bits(self, amount: usize) -> Self {
+                    debug_assert!((1..=(<$type>::BITS as usize)).contains(&amount));
+                    // Truncation is always valid since `amount` is at most 128
+                    #[allow(clippy::cast_possible_truncation)]
+                    let shift = <$type>::BITS - amount as u32;
+                    self.reverse_bits() >> shift
+                }
+            }
+        )+
+    };
+}
+impl_reverse_bits!(u8, u16, u32, u64, u128);
+
 pub trait IsU8Compatible: checks::private::Sealed {}
 pub trait IsU16Compatible: checks::private::Sealed {}
 pub trait IsU32Compatible: checks::private::Sealed {}
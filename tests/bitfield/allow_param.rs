@@ -0,0 +1,17 @@
+//! Tests for `allow = "lints"` #[bitfield] parameter
+
+use modular_bitfield::prelude::*;
+
+#[test]
+#[deny(dead_code)]
+fn allow_param() {
+    // Compiles only if `dead_code` is allowed on the struct and all generated items.
+    #[bitfield(allow = "dead_code, clippy::new_without_default")]
+    struct Unused {
+        a: B4,
+        b: B4,
+    }
+
+    #[bitfield(allow = "dead_code", filled = false)]
+    struct Unused2(bool, B2);
+}
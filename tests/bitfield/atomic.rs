@@ -0,0 +1,51 @@
+//! Tests for the atomic load and store methods of the `atomic` feature.
+
+use core::sync::atomic::{AtomicU32, AtomicU8, Ordering};
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[repr(u32)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Status {
+    ready: bool,
+    error: bool,
+    code: B6,
+    count: B24,
+}
+
+#[bitfield]
+#[repr(u8)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Flags {
+    a: bool,
+    b: B7,
+}
+
+#[test]
+fn round_trip_atomic_u32() {
+    let status = Status::new()
+        .with_ready(true)
+        .with_code(42)
+        .with_count(0xAB_CDEF);
+    let atomic = status.to_atomic();
+    assert_eq!(atomic.load(Ordering::Relaxed), u32::from(status));
+    assert_eq!(Status::load_atomic(&atomic, Ordering::Acquire), status);
+
+    let updated = status.with_error(true).with_count(1);
+    updated.store_atomic(&atomic, Ordering::Release);
+    assert_eq!(Status::load_atomic(&atomic, Ordering::SeqCst), updated);
+
+    let shared = AtomicU32::new(0);
+    status.store_atomic(&shared, Ordering::SeqCst);
+    assert!(Status::load_atomic(&shared, Ordering::Relaxed).ready());
+}
+
+#[test]
+fn round_trip_atomic_u8() {
+    let atomic = AtomicU8::new(0b1000_0011);
+    let flags = Flags::load_atomic(&atomic, Ordering::Relaxed);
+    assert!(flags.a());
+    assert_eq!(flags.b(), 0b100_0001);
+    flags.with_a(false).store_atomic(&atomic, Ordering::Relaxed);
+    assert_eq!(atomic.load(Ordering::Relaxed), 0b1000_0010);
+}
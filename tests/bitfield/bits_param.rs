@@ -72,6 +72,47 @@ fn low_bits_filled() {
     }
 
     assert_eq!(<Header as Specifier>::BITS, 4);
+    assert!(<Header as Specifier>::from_bytes(0b1111).is_ok());
+    assert!(<Header as Specifier>::from_bytes(0b1_0000).is_err());
+}
+
+#[test]
+fn reserved_capacity() {
+    #[bitfield(bits = 20, filled = false)]
+    #[derive(Specifier, Debug)]
+    pub struct Reserved {
+        kind: B5,
+        length: B8,
+    }
+
+    assert_eq!(<Reserved as Specifier>::BITS, 20);
+    assert_eq!(Reserved::ONES.into_bytes(), [0xFF, 0x1F, 0x00]);
+
+    // All defined bits may be set.
+    let reserved = Reserved::from_bytes([0xFF, 0x1F, 0x00]).unwrap();
+    assert_eq!(reserved.kind(), 0b1_1111);
+    assert_eq!(reserved.length(), 0xFF);
+
+    // Reserved bits 13..20 must be zero.
+    for bit in 13..20 {
+        let mut bytes = [0x00; 3];
+        bytes[bit / 8] |= 1 << (bit % 8);
+        assert!(Reserved::from_bytes(bytes).is_err());
+    }
+    // Bits beyond the bit width must be zero, too.
+    assert!(Reserved::from_bytes([0x00, 0x00, 0x10]).is_err());
+
+    let (reserved, had_undefined) = Reserved::from_bytes_reporting([0xFF, 0xFF, 0xFF]);
+    assert!(had_undefined);
+    assert_eq!(reserved.into_bytes(), [0xFF, 0x1F, 0x00]);
+
+    assert!(<Reserved as Specifier>::from_bytes((1 << 13) - 1).is_ok());
+    assert!(<Reserved as Specifier>::from_bytes(1 << 13).is_err());
+    assert!(<Reserved as Specifier>::from_bytes(1 << 19).is_err());
+
+    assert!(Reserved::from_primitive((1 << 13) - 1).is_ok());
+    assert!(Reserved::from_primitive(1 << 13).is_err());
+    assert!(Reserved::from_key(1 << 19).is_err());
 }
 
 #[test]
@@ -113,3 +154,92 @@ fn valid_use_4() {
         value: B31,
     }
 }
+
+#[test]
+fn const_expression() {
+    const HEADER_BITS: usize = 4;
+    const PACKET_BITS: usize = 2 * HEADER_BITS + 8;
+
+    #[bitfield(bits = HEADER_BITS)]
+    #[derive(Specifier)]
+    pub struct Header {
+        kind: B3,
+        is_last: bool,
+    }
+
+    #[bitfield(bits = PACKET_BITS, filled = false)]
+    #[derive(Specifier)]
+    pub struct Packet {
+        header: Header,
+        payload: B8,
+    }
+
+    #[bitfield(bits = PACKET_BITS, bytes = 2)]
+    #[repr(u16)]
+    pub struct Word {
+        high: B8,
+        low: B8,
+    }
+
+    assert_eq!(<Header as Specifier>::BITS, HEADER_BITS);
+    assert_eq!(<Packet as Specifier>::BITS, PACKET_BITS);
+    assert_eq!(u16::from(Word::new().with_high(0xAB)), 0x00AB);
+}
+
+#[test]
+fn range() {
+    const MAX_BITS: usize = 32;
+
+    #[bitfield(bits = 16..=MAX_BITS)]
+    pub struct Header {
+        kind: B4,
+        flags: B4,
+        length: B8,
+    }
+
+    #[bitfield(bits = 8..24)]
+    pub struct Extended {
+        kind: B4,
+        flags: B4,
+        length: B8,
+    }
+
+    #[bitfield(bits = ..8, filled = false)]
+    #[derive(Specifier)]
+    pub struct Small {
+        kind: B4,
+        flags: B2,
+    }
+
+    #[bitfield(bits = 8.., filled = false)]
+    pub struct Large {
+        kind: B4,
+        small: Small,
+    }
+
+    assert_eq!(Header::new().into_bytes().len(), 2);
+    assert_eq!(Extended::new().into_bytes().len(), 2);
+    assert_eq!(<Small as Specifier>::BITS, 6);
+    assert_eq!(Large::new().with_kind(0xF).into_bytes(), [0x0F, 0x00]);
+}
+
+// `#[bits = 1]` is accepted on `bool` fields no matter how `bool` is spelled.
+#[test]
+fn bits_on_bool_fields() {
+    #[bitfield]
+    pub struct Flags {
+        #[bits = 1]
+        is_compact: bool,
+        #[bits = 1]
+        is_secure: ::core::primitive::bool,
+        #[bits = 1]
+        is_last: core::primitive::bool,
+        reserved: B5,
+    }
+
+    let flags = Flags::new().with_is_compact(true).with_is_last(true);
+    assert!(flags.is_compact());
+    assert!(!flags.is_secure());
+    assert!(flags.is_last());
+    assert_eq!(flags.into_bytes(), [0b0000_0101]);
+}
@@ -0,0 +1,40 @@
+//! Tests for `copy` #[bitfield] parameter
+
+use modular_bitfield::prelude::*;
+
+#[test]
+fn copy_param() {
+    #[bitfield(copy)]
+    #[derive(Debug, PartialEq)]
+    struct Register {
+        enabled: bool,
+        mode: B7,
+    }
+
+    #[bitfield(copy, filled = false)]
+    #[derive(Specifier)]
+    struct Flags {
+        a: bool,
+        b: bool,
+    }
+
+    #[bitfield]
+    struct Outer {
+        flags: Flags,
+        rest: B6,
+    }
+
+    fn mode_of(register: Register) -> u8 {
+        register.mode()
+    }
+
+    let register = Register::new().with_enabled(true).with_mode(42);
+    assert_eq!(mode_of(register), 42);
+    assert!(register.enabled());
+    assert_eq!(register, register.clone());
+
+    let flags = Flags::new().with_b(true);
+    let outer = Outer::new().with_flags(flags).with_rest(1);
+    assert!(flags.b());
+    assert!(outer.flags().b());
+}
@@ -1,5 +1,7 @@
 //! Tests specific to the `#[derive(Specifier)]` proc. macro
 
+extern crate alloc;
+use alloc::format;
 use modular_bitfield::prelude::*;
 
 // For some bitfield members, working with them as enums will make more sense to
@@ -166,3 +168,641 @@ fn optional_discriminant() {
     entry.set_delivery_mode(DeliveryMode::Lowest);
     assert_eq!(entry.delivery_mode(), DeliveryMode::Lowest);
 }
+
+// The `#[bits(N)]` form of the attribute accepts constant expressions, which allows
+// deriving the bit width of an enum from other specifiers.
+#[test]
+fn bits_from_expression() {
+    #[derive(Specifier, Debug, PartialEq)]
+    #[bits(<B3 as Specifier>::BITS)]
+    pub enum Level {
+        Low,
+        Medium,
+        High,
+    }
+
+    #[bitfield]
+    pub struct Entry {
+        level: Level,
+        rest: B5,
+    }
+
+    assert_eq!(<Level as Specifier>::BITS, <B3 as Specifier>::BITS);
+
+    let entry = Entry::new().with_level(Level::High).with_rest(0b1_0101);
+    assert_eq!(entry.level(), Level::High);
+    assert_eq!(entry.rest(), 0b1_0101);
+    assert_eq!(entry.into_bytes(), [0b1010_1010]);
+}
+
+// Explicit discriminants with gaps between them are used as the bit pattern as is,
+// while in-range patterns without a variant are rejected as invalid.
+#[test]
+fn gapped_discriminants() {
+    use modular_bitfield::error::InvalidBitPattern;
+
+    #[derive(Specifier, Debug, PartialEq, Clone, Copy)]
+    #[bits = 3]
+    pub enum Sparse {
+        A = 1,
+        B = 4,
+        C = 7,
+    }
+
+    #[bitfield]
+    pub struct Entry {
+        sparse: Sparse,
+        rest: B5,
+    }
+
+    for (variant, pattern) in [(Sparse::A, 1), (Sparse::B, 4), (Sparse::C, 7)] {
+        assert_eq!(<Sparse as Specifier>::into_bytes(variant), Ok(pattern));
+        assert_eq!(<Sparse as Specifier>::from_bytes(pattern), Ok(variant));
+
+        let bytes = Entry::new()
+            .with_sparse(variant)
+            .with_rest(0b1_1111)
+            .into_bytes();
+        assert_eq!(bytes, [0b1111_1000 | pattern]);
+        assert_eq!(Entry::from_bytes(bytes).sparse(), variant);
+    }
+
+    for pattern in [0, 2, 3, 5, 6] {
+        assert_eq!(
+            <Sparse as Specifier>::from_bytes(pattern),
+            Err(InvalidBitPattern::new(pattern))
+        );
+    }
+    let entry = Entry::from_bytes([0b0000_0010]);
+    assert_eq!(entry.sparse_or_err(), Err(InvalidBitPattern::new(2)));
+}
+
+// User derives placed next to `#[derive(Specifier)]` are unaffected by the
+// generated code and allow using the enum as a hash map key.
+#[test]
+fn combined_with_hash_derives() {
+    extern crate std;
+    use std::collections::HashMap;
+
+    #[derive(Specifier, Debug, PartialEq, Eq, Hash, Clone, Copy)]
+    pub enum Color {
+        Red,
+        Green,
+        Blue,
+        Black,
+    }
+
+    #[bitfield]
+    pub struct Pixel {
+        color: Color,
+        intensity: B6,
+    }
+
+    let mut counts = HashMap::new();
+    for bytes in [[0b0000_0100], [0b0100_0001], [0b1111_1101], [0b0000_0011]] {
+        *counts.entry(Pixel::from_bytes(bytes).color()).or_insert(0) += 1;
+    }
+    assert_eq!(counts.len(), 3);
+    assert_eq!(counts[&Color::Red], 1);
+    assert_eq!(counts[&Color::Green], 2);
+    assert_eq!(counts[&Color::Black], 1);
+    assert!(!counts.contains_key(&Color::Blue));
+}
+
+// The compile-time checks generated by `#[derive(Specifier)]` live in anonymous
+// `const _` items, so several derives in the same module never clash.
+#[test]
+fn multiple_derives_in_one_module() {
+    #[derive(Specifier, Debug, PartialEq, Eq, Hash, Clone, Copy)]
+    #[bits = 2]
+    pub enum Mode {
+        Off,
+        On,
+        Auto,
+    }
+
+    #[derive(Specifier, Debug, PartialEq, Eq, Hash, Clone, Copy)]
+    #[specifier(binary)]
+    pub enum Level {
+        Low,
+        High,
+    }
+
+    #[bitfield(bits = 6)]
+    #[derive(Specifier, Debug, Clone, Copy)]
+    pub struct Settings {
+        mode: Mode,
+        level: Level,
+        count: B3,
+    }
+
+    let settings = Settings::new()
+        .with_mode(Mode::Auto)
+        .with_level(Level::High)
+        .with_count(5);
+    assert_eq!(settings.into_bytes(), [0b0010_1110]);
+    assert_eq!(format!("{:b}", Level::High), "1");
+}
+
+// Patterns without a unit variant are read into the `#[catch_all]` variant
+// carrying the raw bits instead of yielding an error.
+#[test]
+fn catch_all_variant() {
+    use modular_bitfield::error::OutOfBounds;
+
+    #[derive(Specifier, Debug, PartialEq, Clone, Copy)]
+    #[bits = 3]
+    #[repr(u8)]
+    pub enum Opcode {
+        Nop,
+        Load = 3,
+        Store,
+        #[catch_all]
+        Unknown(u8),
+        Jump = 7,
+    }
+
+    #[bitfield]
+    pub struct Instruction {
+        opcode: Opcode,
+        operand: B5,
+    }
+
+    for (opcode, pattern) in [
+        (Opcode::Nop, 0),
+        (Opcode::Load, 3),
+        (Opcode::Store, 4),
+        (Opcode::Jump, 7),
+    ] {
+        assert_eq!(<Opcode as Specifier>::into_bytes(opcode), Ok(pattern));
+        assert_eq!(<Opcode as Specifier>::from_bytes(pattern), Ok(opcode));
+    }
+    for pattern in [1, 2, 5, 6] {
+        assert_eq!(
+            <Opcode as Specifier>::from_bytes(pattern),
+            Ok(Opcode::Unknown(pattern))
+        );
+    }
+    assert_eq!(<Opcode as Specifier>::into_bytes(Opcode::Unknown(6)), Ok(6));
+    assert_eq!(
+        <Opcode as Specifier>::into_bytes(Opcode::Unknown(8)),
+        Err(OutOfBounds)
+    );
+
+    let instruction = Instruction::from_bytes([0b1010_1101]);
+    assert_eq!(instruction.opcode(), Opcode::Unknown(5));
+    assert_eq!(instruction.operand(), 0b1_0101);
+    let instruction = Instruction::new().with_opcode(Opcode::Unknown(2));
+    assert_eq!(instruction.into_bytes(), [0b0000_0010]);
+}
+
+// Raw bits beyond the `#[bits = N]` of a `#[catch_all]` enum are rejected so that every
+// decoded value can be encoded again.
+#[test]
+fn catch_all_rejects_unused_bits() {
+    use modular_bitfield::error::InvalidBitPattern;
+
+    const LOAD: u8 = 3;
+
+    #[derive(Specifier, Debug, PartialEq, Clone, Copy)]
+    #[bits = 3]
+    #[repr(u8)]
+    pub enum Opcode {
+        Nop,
+        Load = LOAD,
+        Store,
+        #[catch_all]
+        Unknown(u8),
+    }
+
+    assert_eq!(<Opcode as Specifier>::from_bytes(3), Ok(Opcode::Load));
+    assert_eq!(<Opcode as Specifier>::from_bytes(4), Ok(Opcode::Store));
+    assert_eq!(<Opcode as Specifier>::from_bytes(7), Ok(Opcode::Unknown(7)));
+    for raw in [0b1000, 0xFF] {
+        assert_eq!(
+            <Opcode as Specifier>::from_bytes(raw),
+            Err(InvalidBitPattern::new(raw))
+        );
+    }
+    for raw in 0..8 {
+        let opcode = <Opcode as Specifier>::from_bytes(raw).unwrap();
+        assert_eq!(<Opcode as Specifier>::into_bytes(opcode), Ok(raw));
+    }
+}
+
+// Values of the `#[catch_all]` variant are rejected by the checked setters if they
+// exceed the bit width or alias the pattern of a unit variant.
+#[test]
+fn catch_all_checked_setters() {
+    use modular_bitfield::error::OutOfBounds;
+
+    #[derive(Specifier, Debug, PartialEq, Clone, Copy)]
+    #[bits = 3]
+    #[repr(u8)]
+    pub enum Opcode {
+        Nop,
+        Load = 3,
+        #[catch_all]
+        Unknown(u8),
+    }
+
+    #[bitfield]
+    pub struct Instruction {
+        opcode: Opcode,
+        operand: B5,
+    }
+
+    let instruction = Instruction::new().with_opcode_checked(Opcode::Unknown(5));
+    assert_eq!(instruction.unwrap().opcode(), Opcode::Unknown(5));
+
+    // Out of bounds for 3 bits.
+    assert_eq!(
+        Instruction::new()
+            .with_opcode_checked(Opcode::Unknown(8))
+            .err(),
+        Some(OutOfBounds)
+    );
+    // Aliases the patterns of `Nop` and `Load`.
+    for raw in [0, 3] {
+        let mut instruction = Instruction::new().with_opcode(Opcode::Unknown(1));
+        assert_eq!(
+            instruction.set_opcode_checked(Opcode::Unknown(raw)),
+            Err(OutOfBounds)
+        );
+        assert_eq!(instruction.opcode(), Opcode::Unknown(1));
+    }
+    assert_eq!(
+        <Opcode as Specifier>::into_bytes(Opcode::Unknown(3)),
+        Err(OutOfBounds)
+    );
+}
+
+// A `#[catch_all]` variant wider than a byte makes decoding of forward-compatible
+// protocol fields total.
+#[test]
+fn catch_all_multi_byte() {
+    use modular_bitfield::error::OutOfBounds;
+
+    #[derive(Specifier, Debug, PartialEq, Clone, Copy)]
+    #[bits = 12]
+    #[repr(u16)]
+    pub enum Protocol {
+        Ipv4 = 0x800,
+        Arp = 0x806,
+        #[catch_all]
+        Other(u16),
+    }
+
+    #[bitfield]
+    pub struct Frame {
+        protocol: Protocol,
+        flags: B4,
+    }
+
+    let frame = Frame::new().with_protocol(Protocol::Arp).with_flags(0xF);
+    assert_eq!(frame.into_bytes(), [0x06, 0xF8]);
+    let frame = Frame::from_bytes([0xDD, 0x16]);
+    assert_eq!(frame.protocol(), Protocol::Other(0x6DD));
+    assert_eq!(frame.flags(), 1);
+    assert_eq!(
+        <Protocol as Specifier>::from_bytes(0x800),
+        Ok(Protocol::Ipv4)
+    );
+    assert_eq!(
+        Frame::new()
+            .with_protocol_checked(Protocol::Other(0x806))
+            .err(),
+        Some(OutOfBounds)
+    );
+    assert_eq!(
+        Frame::new()
+            .with_protocol_checked(Protocol::Other(0x1000))
+            .err(),
+        Some(OutOfBounds)
+    );
+}
+
+#[test]
+fn min_and_max_variants() {
+    #[derive(Specifier, Debug, PartialEq)]
+    #[specifier(min_max)]
+    #[bits = 3]
+    enum Color {
+        Red = 2,
+        Green = 6,
+        Blue = 0,
+        Yellow,
+    }
+
+    #[derive(Specifier, Debug, PartialEq)]
+    #[specifier(min_max)]
+    #[bits = 2]
+    enum Version {
+        V1,
+        V2,
+        #[catch_all]
+        Unknown(u8),
+    }
+
+    assert_eq!(Color::MIN, Color::Blue);
+    assert_eq!(Color::MAX, Color::Green);
+    assert_eq!(Version::MIN, Version::V1);
+    assert_eq!(Version::MAX, Version::V2);
+}
+
+#[test]
+fn min_max_is_opt_in() {
+    #[derive(Specifier, Debug, PartialEq)]
+    enum Mode {
+        Sleep,
+        Busy,
+    }
+
+    impl Mode {
+        const MAX: Self = Mode::Sleep;
+    }
+
+    assert_eq!(Mode::MAX, Mode::Sleep);
+}
+
+#[test]
+fn iter_variants() {
+    #[derive(Specifier, Debug, PartialEq)]
+    #[specifier(iter_variants)]
+    #[bits = 3]
+    enum Color {
+        Red = 2,
+        Green = 6,
+        Blue = 0,
+        Yellow,
+    }
+
+    #[derive(Specifier, Debug, PartialEq)]
+    #[specifier(iter_variants)]
+    #[bits = 2]
+    enum Version {
+        V1,
+        V2,
+        #[catch_all]
+        Unknown(u8),
+    }
+
+    assert!(Color::iter_variants().eq([Color::Red, Color::Green, Color::Blue, Color::Yellow]));
+    assert!(Version::iter_variants().eq([Version::V1, Version::V2]));
+    for color in Color::iter_variants() {
+        let bytes = <Color as Specifier>::into_bytes(color).unwrap();
+        assert!(<Color as Specifier>::from_bytes(bytes).is_ok());
+    }
+}
+
+#[test]
+fn iter_variants_is_opt_in() {
+    #[derive(Specifier, Debug, PartialEq)]
+    enum Mode {
+        Sleep,
+        Busy,
+    }
+
+    impl Mode {
+        fn iter_variants() -> impl Iterator<Item = Self> {
+            [Mode::Busy].into_iter()
+        }
+    }
+
+    assert!(Mode::iter_variants().eq([Mode::Busy]));
+}
+
+#[test]
+fn from_bytes_unchecked() {
+    #[derive(Specifier, Debug, PartialEq)]
+    #[specifier(unchecked, iter_variants)]
+    #[bits = 3]
+    enum Color {
+        Red = 2,
+        Green = 6,
+        Blue = 0,
+        Yellow,
+    }
+
+    for color in Color::iter_variants() {
+        let bytes = <Color as Specifier>::into_bytes(color).unwrap();
+        // SAFETY: `bytes` is the bit pattern of a variant.
+        let unchecked = unsafe { Color::from_bytes_unchecked(bytes) };
+        assert_eq!(Ok(unchecked), <Color as Specifier>::from_bytes(bytes));
+    }
+}
+
+#[test]
+fn from_str() {
+    use modular_bitfield::error::UnknownVariant;
+
+    #[derive(Specifier, Debug, PartialEq)]
+    #[specifier(from_str)]
+    #[bits = 2]
+    enum Mode {
+        Sleep,
+        Idle,
+        r#Busy,
+    }
+
+    #[derive(Specifier, Debug, PartialEq)]
+    #[specifier(from_str)]
+    #[bits = 2]
+    enum Version {
+        V1,
+        #[catch_all]
+        Unknown(u8),
+    }
+
+    assert_eq!("Sleep".parse(), Ok(Mode::Sleep));
+    assert_eq!("Idle".parse(), Ok(Mode::Idle));
+    assert_eq!("Busy".parse(), Ok(Mode::Busy));
+    assert_eq!("idle".parse::<Mode>(), Err(UnknownVariant));
+    assert_eq!("Off".parse::<Mode>(), Err(UnknownVariant));
+    assert_eq!("V1".parse(), Ok(Version::V1));
+    assert_eq!("Unknown".parse::<Version>(), Err(UnknownVariant));
+}
+
+#[test]
+fn bool_conversions() {
+    #[derive(Specifier, Debug, PartialEq, Clone, Copy)]
+    #[specifier(bool)]
+    enum Level {
+        Low,
+        High,
+    }
+
+    #[derive(Specifier, Debug, PartialEq, Clone, Copy)]
+    #[specifier(bool)]
+    #[repr(u8)]
+    enum Polarity {
+        ActiveHigh = 1,
+        ActiveLow = 0,
+    }
+
+    #[bitfield]
+    struct Pin {
+        level: Level,
+        polarity: Polarity,
+        enabled: bool,
+        number: B5,
+    }
+
+    assert!(!bool::from(Level::Low));
+    assert!(bool::from(Level::High));
+    assert_eq!(Level::from(true), Level::High);
+    assert_eq!(Polarity::from(false), Polarity::ActiveLow);
+    assert!(bool::from(Polarity::ActiveHigh));
+
+    let pin = Pin::new()
+        .with_level(Level::from(true))
+        .with_enabled(bool::from(Polarity::ActiveHigh));
+    assert_eq!(pin.level(), Level::High);
+    assert!(pin.enabled());
+    assert_eq!(bool::from(pin.level()), pin.enabled());
+}
+
+#[test]
+fn binary_format() {
+    #[derive(Specifier, Debug, PartialEq)]
+    #[specifier(binary)]
+    enum Level {
+        L0,
+        L1,
+        L2,
+        L3,
+        L4,
+        L5,
+        L6,
+        L7,
+    }
+
+    #[derive(Specifier, Debug, PartialEq)]
+    #[specifier(binary)]
+    #[bits = 4]
+    enum Version {
+        V1,
+        V2,
+        #[catch_all]
+        Unknown(u8),
+    }
+
+    assert_eq!(format!("{:#b}", Level::L5), "0b101");
+    assert_eq!(format!("{:b}", Level::L1), "001");
+    assert_eq!(format!("{:#b}", Version::V2), "0b0001");
+    assert_eq!(format!("{:b}", Version::Unknown(0b1010)), "1010");
+}
+
+#[test]
+fn signed_repr_round_trip() {
+    #[derive(Specifier, Debug, PartialEq)]
+    #[repr(i8)]
+    #[bits = 2]
+    enum Step {
+        Zero = 0,
+        One = 1,
+        Three = 3,
+    }
+
+    #[bitfield(filled = false)]
+    struct Sample {
+        step: Step,
+        flag: bool,
+    }
+
+    for step in [Step::Zero, Step::One, Step::Three] {
+        let bytes = <Step as Specifier>::into_bytes(step).unwrap();
+        assert_eq!(
+            <Step as Specifier>::from_bytes(bytes).unwrap() as i8,
+            i8::try_from(bytes).unwrap()
+        );
+    }
+    let sample = Sample::new().with_step(Step::Three).with_flag(true);
+    assert_eq!(sample.step(), Step::Three);
+    assert_eq!(sample.into_bytes(), [0b111]);
+}
+
+// `#[bits]` may be narrower than or as wide as the `#[repr(..)]` of the enum.
+#[test]
+fn bits_within_repr() {
+    #[derive(Specifier, Debug, PartialEq)]
+    #[repr(u8)]
+    #[bits = 8]
+    pub enum Full {
+        A = 0,
+        B = 255,
+    }
+
+    #[derive(Specifier, Debug, PartialEq)]
+    #[repr(u8)]
+    #[bits = 4]
+    pub enum Narrow {
+        A = 0,
+        B = 15,
+    }
+
+    #[derive(Specifier, Debug, PartialEq)]
+    #[repr(u16)]
+    #[bits(<B12 as Specifier>::BITS)]
+    pub enum Expression {
+        A = 0,
+        B = 4095,
+    }
+
+    assert_eq!(<Full as Specifier>::BITS, 8);
+    assert_eq!(<Full as Specifier>::from_bytes(255), Ok(Full::B));
+    assert_eq!(<Narrow as Specifier>::BITS, 4);
+    assert_eq!(<Narrow as Specifier>::from_bytes(15), Ok(Narrow::B));
+    assert_eq!(<Expression as Specifier>::BITS, 12);
+    assert_eq!(
+        <Expression as Specifier>::into_bytes(Expression::B),
+        Ok(4095)
+    );
+}
+
+// The `#[repr(..)]` of an enum, e.g. for FFI, does not affect how many bits it occupies
+// within a bitfield.
+#[test]
+fn ffi_repr_packs_to_minimum_bits() {
+    #[derive(Specifier, Debug, PartialEq, Clone, Copy)]
+    #[repr(u32)]
+    #[bits = 3]
+    pub enum Command {
+        Nop = 0,
+        Read = 1,
+        Write = 2,
+        Erase = 4,
+        Reset = 7,
+    }
+
+    #[derive(Specifier, Debug, PartialEq, Clone, Copy)]
+    #[repr(C)]
+    pub enum Level {
+        Low,
+        High,
+    }
+
+    #[bitfield]
+    pub struct Request {
+        command: Command,
+        level: Level,
+        address: B12,
+    }
+
+    assert_eq!(<Command as Specifier>::BITS, 3);
+    assert_eq!(<Level as Specifier>::BITS, 1);
+    assert_eq!(Command::Reset as u32, 7);
+    assert_eq!(<Command as Specifier>::into_bytes(Command::Erase), Ok(4_u8));
+    assert_eq!(<Command as Specifier>::from_bytes(7), Ok(Command::Reset));
+    assert!(<Command as Specifier>::from_bytes(5).is_err());
+
+    let request = Request::new()
+        .with_command(Command::Reset)
+        .with_level(Level::High)
+        .with_address(0xABC);
+    assert_eq!(request.into_bytes(), [0b1100_1111, 0xAB]);
+    let request = Request::from_bytes([0b0000_0100, 0x00]);
+    assert_eq!(request.command(), Command::Erase);
+    assert_eq!(request.level(), Level::Low);
+    assert_eq!(request.address(), 0);
+}
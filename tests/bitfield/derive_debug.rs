@@ -152,3 +152,73 @@ fn valid_use_tuple() {
         "Color(\n    0x3f,\n    0x20,\n    0x10,\n    0x8,\n)",
     );
 }
+
+#[test]
+fn debug_bytes() {
+    #[bitfield]
+    pub struct Raw {
+        low: B8,
+        high: B8,
+    }
+
+    let raw = Raw::new().with_low(0x12).with_high(0xAB);
+    assert_eq!(format!("{:?}", raw.debug_bytes()), "[0x12, 0xAB]");
+    assert_eq!(
+        format!("{:#?}", raw.debug_bytes()),
+        "[\n    0x12,\n    0xAB,\n]"
+    );
+}
+
+#[test]
+fn fields_debug() {
+    #[derive(Specifier, Debug)]
+    #[bits = 2]
+    pub enum Status {
+        Green = 0,
+        Yellow = 1,
+        Red = 2,
+    }
+
+    #[bitfield]
+    #[derive(Debug)]
+    pub struct DataPackage {
+        status: Status,
+        contents: B4,
+        is_alive: bool,
+        is_received: bool,
+    }
+
+    #[bitfield]
+    pub struct Untraced(B4, bool, B3);
+
+    // Field types without `Debug` do not prevent the bitfield from compiling.
+    #[derive(Specifier)]
+    pub enum Opaque {
+        A,
+        B,
+    }
+
+    #[bitfield]
+    pub struct WithOpaque {
+        opaque: Opaque,
+        rest: B7,
+    }
+
+    for bytes in [[0b0101_1011], [0b1001_0110]] {
+        let package = DataPackage::from_bytes(bytes);
+        assert_eq!(
+            format!("{:?}", package.fields_debug()),
+            format!("{package:?}")
+        );
+        assert_eq!(
+            format!("{:#X?}", package.fields_debug()),
+            format!("{package:#X?}")
+        );
+    }
+    let untraced = Untraced::new().with_0(9).with_1(true).with_2(5);
+    assert_eq!(
+        format!("{:?}", untraced.fields_debug()),
+        "Untraced(9, true, 5)"
+    );
+    assert_eq!(WithOpaque::new().with_rest(3).rest(), 3);
+}
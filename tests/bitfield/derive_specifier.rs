@@ -67,3 +67,41 @@ fn valid_use() {
 
     assert_eq!(<Header as Specifier>::BITS, 8);
 }
+
+#[test]
+fn newtype_wrapper() {
+    use modular_bitfield::error::{InvalidBitPattern, OutOfBounds};
+
+    #[derive(Specifier, Debug, PartialEq, Eq, Copy, Clone)]
+    pub struct Celsius(u8);
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    fn is_percentage(value: &u8) -> bool {
+        *value <= 100
+    }
+
+    #[derive(Specifier, Debug, PartialEq, Eq, Copy, Clone)]
+    #[specifier(validate = is_percentage)]
+    pub struct Percentage(u8);
+
+    #[bitfield]
+    #[derive(Debug)]
+    pub struct Reading {
+        temperature: Celsius,
+        humidity: Percentage,
+    }
+
+    assert_eq!(<Celsius as Specifier>::BITS, 8);
+    let mut reading = Reading::new()
+        .with_temperature(Celsius(21))
+        .with_humidity(Percentage(45));
+    assert_eq!(reading.temperature(), Celsius(21));
+    assert_eq!(reading.humidity(), Percentage(45));
+    assert_eq!(
+        reading.set_humidity_checked(Percentage(101)),
+        Err(OutOfBounds)
+    );
+    assert_eq!(reading.into_bytes(), [21, 45]);
+    let reading = Reading::from_bytes([21, 200]);
+    assert_eq!(reading.humidity_or_err(), Err(InvalidBitPattern::new(200)));
+}
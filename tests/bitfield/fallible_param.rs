@@ -0,0 +1,51 @@
+//! Tests for `fallible = true` #[bitfield] parameter
+
+use modular_bitfield::prelude::*;
+
+#[test]
+fn fallible_accessors() {
+    extern crate alloc;
+    use alloc::format;
+    use modular_bitfield::error::OutOfBounds;
+
+    #[derive(Specifier, Debug, PartialEq)]
+    #[bits = 2]
+    enum Mode {
+        A = 0,
+        B = 1,
+        C = 2,
+    }
+
+    #[bitfield(fallible = true)]
+    #[derive(Debug)]
+    struct Fallible {
+        flag: bool,
+        mode: Mode,
+        value: B5,
+    }
+
+    let mut fallible = Fallible::new();
+    let flag: Result<bool, _> = fallible.flag();
+    assert_eq!(flag, Ok(false));
+    let set: Result<(), OutOfBounds> = fallible.set_value(31);
+    assert_eq!(set, Ok(()));
+    assert_eq!(fallible.value(), Ok(31));
+    assert_eq!(fallible.set_value(32), Err(OutOfBounds));
+    assert_eq!(fallible.value(), Ok(31));
+
+    let fallible = fallible
+        .with_mode(Mode::C)
+        .unwrap()
+        .with_flag(true)
+        .unwrap();
+    assert_eq!(fallible.mode(), Ok(Mode::C));
+    assert_eq!(fallible.flag(), Ok(true));
+    assert!(Fallible::new().with_value(0xFF).is_err());
+
+    let invalid = Fallible::from_bytes([0b0000_0110]);
+    assert!(invalid.mode().is_err());
+    assert_eq!(
+        format!("{invalid:?}"),
+        "Fallible { flag: false, mode: InvalidBitPattern { invalid_bytes: 3 }, value: 0 }",
+    );
+}
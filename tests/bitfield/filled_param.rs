@@ -52,3 +52,110 @@ fn valid_bitfield_specifier_2() {
         Ok(0x0003_0201)
     );
 }
+
+#[test]
+fn from_bytes_reporting() {
+    #[bitfield(filled = false)]
+    #[derive(Debug, PartialEq)]
+    pub struct Unfilled {
+        a: B8,
+        b: B4,
+    }
+
+    let (value, had_undefined) = Unfilled::from_bytes_reporting([0xAB, 0x0C]);
+    assert!(!had_undefined);
+    assert_eq!(value, Unfilled::new().with_a(0xAB).with_b(0xC));
+    assert_eq!(Unfilled::from_bytes([0xAB, 0x0C]), Ok(value));
+
+    let (value, had_undefined) = Unfilled::from_bytes_reporting([0xAB, 0x5C]);
+    assert!(had_undefined);
+    assert_eq!(value.a(), 0xAB);
+    assert_eq!(value.b(), 0xC);
+    assert_eq!(value.into_bytes(), [0xAB, 0x0C]);
+}
+
+#[test]
+fn zero_and_ones_constants() {
+    #[bitfield]
+    pub struct Filled {
+        a: B4,
+        b: B12,
+    }
+
+    #[bitfield(filled = false)]
+    pub struct Unfilled {
+        a: B4,
+        b: B7,
+    }
+
+    const FILLED_ZERO: Filled = Filled::ZERO;
+    assert_eq!(FILLED_ZERO.into_bytes(), Filled::new().into_bytes());
+    assert_eq!(Filled::ONES.into_bytes(), [0xFF, 0xFF]);
+    assert_eq!(Filled::ONES.b(), 0x0FFF);
+
+    assert_eq!(Unfilled::ZERO.into_bytes(), [0x00, 0x00]);
+    assert_eq!(Unfilled::ONES.into_bytes(), [0xFF, 0b0000_0111]);
+    assert_eq!(Unfilled::ONES.a(), 0xF);
+    assert_eq!(Unfilled::ONES.b(), 0x7F);
+    assert!(Unfilled::from_bytes(Unfilled::ONES.into_bytes()).is_ok());
+}
+
+#[test]
+fn bytes_constant() {
+    #[bitfield]
+    pub struct Filled {
+        a: B4,
+        b: B20,
+    }
+
+    #[bitfield(filled = false)]
+    pub struct Unfilled {
+        a: B4,
+        b: B13,
+    }
+
+    assert_eq!(Filled::BYTES, 3);
+    assert_eq!(Filled::BYTES, Filled::new().into_bytes().len());
+    assert_eq!(Unfilled::BYTES, 3);
+    assert_eq!(Unfilled::BYTES, Unfilled::new().into_bytes().len());
+
+    let buffer = [0_u8; Filled::BYTES];
+    assert_eq!(Filled::from_bytes(buffer).b(), 0);
+}
+
+#[test]
+fn derive_partial_eq_ignores_undefined_bits() {
+    #[bitfield(filled = false)]
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    pub struct Unfilled {
+        a: B4,
+        b: B7,
+    }
+
+    #[bitfield]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Filled {
+        a: B4,
+        b: B4,
+    }
+
+    let unfilled = Unfilled::new().with_a(3).with_b(0x7F);
+    assert_eq!(unfilled, Unfilled::from_bytes([0x03 | 0xF0, 0x07]).unwrap());
+    assert_eq!(unfilled, Unfilled::ONES.with_a(3));
+    assert_ne!(unfilled, unfilled.with_b(0));
+    assert_eq!(unfilled, [0xF3, 0xFF]);
+    assert_eq!(Filled::new().with_a(1), Filled::from_bytes([0x01]));
+    assert_ne!(Filled::new().with_a(1), Filled::new().with_b(1));
+
+    // `from_bytes` rejects undefined bits, so values differing only in their undefined
+    // high bits are created through the `#[repr(transparent)]` byte array instead.
+    assert!(Unfilled::from_bytes([0x03, 0xF8]).is_err());
+    let defined = Unfilled::from_bytes([0x03, 0x00]).unwrap();
+    // SAFETY: `Unfilled` is `#[repr(transparent)]` over `[u8; 2]`.
+    let undefined_set = unsafe { core::mem::transmute::<[u8; 2], Unfilled>([0x03, 0xF8]) };
+    // SAFETY: `Unfilled` is `#[repr(transparent)]` over `[u8; 2]`.
+    let undefined_other = unsafe { core::mem::transmute::<[u8; 2], Unfilled>([0x03, 0x28]) };
+    assert_eq!(defined, undefined_set);
+    assert_eq!(undefined_set, undefined_other);
+    assert_ne!(undefined_set, undefined_other.with_a(4));
+}
@@ -0,0 +1,27 @@
+//! Tests for `getters: bool` and `setters: bool` #[bitfield] parameters
+
+extern crate alloc;
+use alloc::format;
+use modular_bitfield::prelude::*;
+
+#[test]
+fn read_only_struct() {
+    #[bitfield(setters = false)]
+    #[derive(Debug)]
+    pub struct Status {
+        ready: bool,
+        error: bool,
+        #[skip(getters)]
+        __: B2,
+        code: B4,
+    }
+
+    let status = Status::from_bytes([0b1010_0001]);
+    assert!(status.ready());
+    assert!(!status.error());
+    assert_eq!(status.code(), 0b1010);
+    assert_eq!(
+        format!("{status:?}"),
+        "Status { ready: true, error: false, code: 10 }"
+    );
+}
@@ -0,0 +1,25 @@
+//! Tests for `index` #[bitfield] parameter
+
+use modular_bitfield::prelude::*;
+
+#[test]
+fn index_param() {
+    #[bitfield(index)]
+    struct Interrupts {
+        timer: bool,
+        uart: bool,
+        spi: bool,
+        reserved: B5,
+        priority: B8,
+    }
+
+    let interrupts = Interrupts::new()
+        .with_uart(true)
+        .with_spi(true)
+        .with_priority(0x80);
+    assert!(!interrupts[0]);
+    assert!(interrupts[1]);
+    assert!(interrupts[2]);
+    assert!((3..15).all(|n| !interrupts[n]));
+    assert!(interrupts[15]);
+}
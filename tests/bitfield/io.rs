@@ -0,0 +1,57 @@
+//! Tests for `read_from` and `write_to` of the `std` feature.
+
+extern crate std;
+
+use modular_bitfield::{error::OutOfBounds, prelude::*};
+use std::{
+    io::{Cursor, ErrorKind},
+    vec::Vec,
+};
+
+#[bitfield]
+#[derive(Debug, PartialEq)]
+pub struct Header {
+    version: B4,
+    kind: B4,
+    length: B16,
+}
+
+#[bitfield(filled = false)]
+#[derive(Debug, PartialEq)]
+pub struct Flags {
+    a: bool,
+    b: B5,
+}
+
+#[test]
+fn round_trip_through_cursor() {
+    let first = Header::new()
+        .with_version(1)
+        .with_kind(7)
+        .with_length(0x1234);
+    let second = Header::new().with_version(15).with_length(0xFFFF);
+
+    let mut buffer = Cursor::new(Vec::new());
+    first.write_to(&mut buffer).unwrap();
+    second.write_to(&mut buffer).unwrap();
+    assert_eq!(buffer.get_ref(), &[0x71, 0x34, 0x12, 0x0F, 0xFF, 0xFF]);
+
+    buffer.set_position(0);
+    assert_eq!(Header::read_from(&mut buffer).unwrap(), first);
+    assert_eq!(Header::read_from(&mut buffer).unwrap(), second);
+    let error = Header::read_from(&mut buffer).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn invalid_data() {
+    let mut reader = Cursor::new([0b0011_1111, 0b0100_0000]);
+    assert_eq!(
+        Flags::read_from(&mut reader).unwrap(),
+        Flags::new().with_a(true).with_b(0b1_1111)
+    );
+    let error = Flags::read_from(&mut reader).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+    let source = error.into_inner().unwrap();
+    assert_eq!(source.downcast_ref::<OutOfBounds>(), Some(&OutOfBounds));
+}
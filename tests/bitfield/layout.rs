@@ -0,0 +1,304 @@
+//! Golden byte tests for the generated bitfield layout.
+//!
+//! The byte representation of a `#[bitfield]` struct is part of its public contract,
+//! e.g. when used in FFI. These tests pin the encoding of representative structs so
+//! that accidental layout changes are caught.
+
+use modular_bitfield::prelude::*;
+
+/// Asserts that the bitfield encodes to the golden bytes and decodes from them.
+macro_rules! assert_layout {
+    ($value:expr, $ty:ty, $golden:expr $(,)?) => {{
+        let golden = $golden;
+        assert_eq!($value.into_bytes(), golden);
+        assert_eq!(<$ty>::from_bytes(golden).into_bytes(), golden);
+    }};
+}
+
+#[test]
+fn documented_example() {
+    #[bitfield]
+    pub struct MyFourBytes {
+        a: B1,
+        b: B3,
+        c: B4,
+        d: B24,
+    }
+
+    let value = MyFourBytes::new()
+        .with_a(1)
+        .with_b(0b101)
+        .with_c(0b1100)
+        .with_d(0x12_3456);
+    assert_layout!(value, MyFourBytes, [0xCB, 0x56, 0x34, 0x12]);
+}
+
+#[test]
+fn unaligned_fields() {
+    #[bitfield]
+    pub struct Unaligned {
+        a: B3,
+        b: B10,
+        c: B11,
+    }
+
+    let value = Unaligned::new().with_a(0b101).with_b(0x26D).with_c(0x5A3);
+    assert_layout!(value, Unaligned, [0x6D, 0x73, 0xB4]);
+}
+
+#[test]
+fn enum_and_bool_fields() {
+    #[derive(Specifier, Debug, PartialEq, Eq)]
+    pub enum Mode {
+        A,
+        B,
+        C,
+        D,
+    }
+
+    #[bitfield]
+    pub struct Control {
+        mode: Mode,
+        enabled: bool,
+        level: B5,
+    }
+
+    let value = Control::new()
+        .with_mode(Mode::C)
+        .with_enabled(true)
+        .with_level(0b10110);
+    assert_layout!(value, Control, [0xB6]);
+}
+
+#[test]
+fn nested_bitfield() {
+    #[bitfield(bits = 4)]
+    #[derive(Specifier)]
+    pub struct Header {
+        is_a: bool,
+        is_b: bool,
+        kind: B2,
+    }
+
+    #[bitfield]
+    pub struct Packet {
+        header: Header,
+        body: B13,
+        tail: B7,
+        flag: bool,
+        rest: B7,
+    }
+
+    let header = Header::new()
+        .with_is_a(true)
+        .with_is_b(true)
+        .with_kind(0b10);
+    let value = Packet::new()
+        .with_header(header)
+        .with_body(0x1234)
+        .with_tail(0x7F)
+        .with_flag(true)
+        .with_rest(0x2A);
+    assert_layout!(value, Packet, [0x4B, 0x23, 0xFF, 0x55]);
+}
+
+#[test]
+fn nested_multi_byte_bitfield() {
+    // The bytes of a nested bitfield are embedded in the same little-endian bit order
+    // as the fields of the outer bitfield, even when they straddle byte boundaries.
+    #[bitfield]
+    #[derive(Specifier)]
+    pub struct Inner {
+        lo: B8,
+        hi: B8,
+    }
+
+    #[bitfield]
+    pub struct Outer {
+        a: B4,
+        inner: Inner,
+        b: B4,
+    }
+
+    assert_eq!(
+        Inner::new().with_lo(0xAB).with_hi(0xCD).into_bytes(),
+        [0xAB, 0xCD]
+    );
+    let inner = Inner::new().with_lo(0xAB).with_hi(0xCD);
+    let value = Outer::new().with_a(0x1).with_inner(inner).with_b(0xE);
+    assert_layout!(value, Outer, [0xB1, 0xDA, 0xEC]);
+}
+
+#[test]
+fn wide_fields() {
+    #[bitfield]
+    pub struct Wide {
+        low: B4,
+        mid: B60,
+        high: B64,
+    }
+
+    let value = Wide::new()
+        .with_low(0xA)
+        .with_mid(0x0123_4567_89AB_CDEF)
+        .with_high(0xFEDC_BA98_7654_3210);
+    assert_layout!(
+        value,
+        Wide,
+        [
+            0xFA, 0xDE, 0xBC, 0x9A, 0x78, 0x56, 0x34, 0x12, 0x10, 0x32, 0x54, 0x76, 0x98, 0xBA,
+            0xDC, 0xFE,
+        ],
+    );
+}
+
+#[test]
+fn repr_primitive() {
+    #[bitfield]
+    #[repr(u32)]
+    pub struct Register {
+        a: B1,
+        b: B3,
+        c: B4,
+        d: B24,
+    }
+
+    let value = Register::new()
+        .with_a(1)
+        .with_b(0b101)
+        .with_c(0b1100)
+        .with_d(0x12_3456);
+    assert_eq!(u32::from(value), 0x1234_56CB);
+    assert_layout!(
+        Register::from(0x1234_56CB),
+        Register,
+        [0xCB, 0x56, 0x34, 0x12]
+    );
+}
+
+#[test]
+fn explicit_positions() {
+    // Fields are grouped by function instead of being listed in memory order.
+    #[bitfield]
+    pub struct Scrambled {
+        #[at(bit = 8)]
+        d: B24,
+        #[at(bit = 1)]
+        b: B3,
+        #[at(bit = 0)]
+        a: B1,
+        #[at(bit = 4)]
+        c: B4,
+    }
+
+    let value = Scrambled::new()
+        .with_a(1)
+        .with_b(0b101)
+        .with_c(0b1100)
+        .with_d(0x12_3456);
+    assert_layout!(value, Scrambled, [0xCB, 0x56, 0x34, 0x12]);
+
+    let value = Scrambled::from_bytes([0xCB, 0x56, 0x34, 0x12]);
+    assert_eq!(value.a(), 1);
+    assert_eq!(value.b(), 0b101);
+    assert_eq!(value.c(), 0b1100);
+    assert_eq!(value.d(), 0x12_3456);
+}
+
+#[test]
+fn doc_layout_table() {
+    #[derive(Specifier)]
+    pub enum Mode {
+        A,
+        B,
+        C,
+        D,
+    }
+
+    #[bitfield(doc_layout)]
+    pub struct Register {
+        enabled: bool,
+        #[bits = 2]
+        mode: Mode,
+        #[padding(1)]
+        _reserved: (),
+        divider: B12,
+    }
+
+    #[bitfield(doc_layout)]
+    pub struct Unknown {
+        mode: Mode,
+        rest: B6,
+        byte: u8,
+    }
+
+    assert_eq!(
+        Register::LAYOUT,
+        "# Layout\n\n\
+         | Field | Offset | Width | Type |\n\
+         |-------|--------|-------|------|\n\
+         | `enabled` | 0 | 1 | `bool` |\n\
+         | `mode` | 1 | 2 | `Mode` |\n\
+         | `_reserved` | 3 | 1 | `::modular_bitfield::specifiers::B1` |\n\
+         | `divider` | 4 | 12 | `B12` |\n"
+    );
+    assert!(Unknown::LAYOUT.contains("| `mode` | 0 | `<Mode as Specifier>::BITS` | `Mode` |\n"));
+    assert!(Unknown::LAYOUT.contains("| `byte` | 6 + `<Mode as Specifier>::BITS` | 8 | `u8` |\n"));
+}
+
+#[test]
+fn assert_field_layout() {
+    use modular_bitfield::assert_field_layout;
+
+    #[derive(Specifier, Debug, PartialEq, Eq, Copy, Clone)]
+    pub enum Mode {
+        A = 0b00,
+        B = 0b01,
+        C = 0b10,
+        D = 0b11,
+    }
+
+    #[bitfield(field_layout)]
+    pub struct Register {
+        enabled: bool,
+        mode: Mode,
+        #[skip]
+        reserved: B5,
+        count: B24,
+    }
+
+    #[bitfield(field_layout)]
+    pub struct Placed {
+        #[at(bit = 4)]
+        high: B4,
+        #[at(bit = 0)]
+        low: B4,
+    }
+
+    #[bitfield(field_layout)]
+    pub struct Pair(B3, B5);
+
+    assert_field_layout!(Register,
+        enabled => 0..1,
+        mode => 1..3,
+        reserved => 3..8,
+        count => 8..32,
+    );
+    assert_field_layout!(Placed, low => 0..4, high => 4..8);
+    assert_field_layout!(Pair, 0 => 0..3, 1 => 3..8);
+}
+
+#[test]
+fn field_layout_is_opt_in() {
+    // Compiles only if no hidden layout struct is generated without `field_layout`.
+    #[allow(dead_code)]
+    struct __PacketBitfieldLayout;
+
+    #[bitfield]
+    pub struct Packet {
+        low: B4,
+        high: B4,
+    }
+
+    assert_eq!(Packet::new().with_high(3).into_bytes(), [0x30]);
+}
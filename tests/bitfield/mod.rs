@@ -1,13 +1,30 @@
+mod allow_param;
+#[cfg(feature = "atomic")]
+mod atomic;
 mod bits_param;
 mod bytes_param;
+mod copy_param;
 mod derive_bitfield_specifier;
 mod derive_debug;
 mod derive_specifier;
+mod fallible_param;
 mod filled_param;
+mod getters_setters_param;
+mod index_param;
+#[cfg(feature = "std")]
+mod io;
+mod layout;
+#[cfg(feature = "std")]
+mod net;
 mod no_implicit_prelude;
+mod pad_to_pow2;
+mod prop_test_param;
 mod regressions;
+mod rename_accessors_param;
 mod repr;
 mod skip;
+mod transparent_param;
+mod warn_waste_param;
 
 use modular_bitfield::prelude::*;
 
@@ -576,3 +593,1271 @@ fn impl_try_from_trait() {
     let v: [u8; 1] = v.into();
     assert_eq!(v, [127]);
 }
+
+#[test]
+fn primitive_conversions() {
+    use modular_bitfield::error::OutOfBounds;
+
+    #[bitfield(filled = false)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Test {
+        a: B4,
+        b: B16,
+    }
+
+    let v = Test::new().with_a(0x3).with_b(0xABCD);
+    let prim: u32 = v.into_primitive();
+    assert_eq!(prim, 0x000A_BCD3);
+    assert_eq!(Test::from_primitive(prim), Ok(v));
+    assert_eq!(Test::from_primitive(0x000F_FFFF).unwrap().b(), 0xFFFF);
+    assert_eq!(Test::from_primitive(0x0010_0000), Err(OutOfBounds));
+}
+
+#[test]
+fn get_field_as_other_specifier() {
+    #[derive(Specifier, Debug, PartialEq)]
+    enum Direction {
+        North,
+        East,
+        South,
+        West,
+    }
+
+    #[bitfield(as_getters)]
+    struct Packet {
+        dir: B2,
+        speed: B6,
+    }
+
+    #[bitfield(as_getters)]
+    struct Raw(B2, B6);
+
+    let packet = Packet::new().with_dir(2).with_speed(42);
+    assert_eq!(packet.dir_as::<Direction>(), Ok(Direction::South));
+    assert_eq!(packet.speed_as::<B6>(), Ok(42));
+    assert_eq!(packet.dir(), 2);
+
+    let raw = Raw::new().with_0(3);
+    assert_eq!(raw.get_0_as::<Direction>(), Ok(Direction::West));
+}
+
+#[test]
+fn as_getters_are_opt_in() {
+    // Compiles only if `x_as` does not collide with the getter of the field `x_as`.
+    #[bitfield]
+    struct Pair {
+        x: B4,
+        x_as: B4,
+    }
+
+    let pair = Pair::new().with_x(1).with_x_as(2);
+    assert_eq!(pair.x_as(), 2);
+}
+
+#[test]
+fn eq_raw_bytes() {
+    #[bitfield]
+    #[derive(Debug)]
+    struct Filled {
+        a: B4,
+        b: B12,
+    }
+
+    #[bitfield(filled = false)]
+    #[derive(Debug)]
+    struct Unfilled {
+        a: B4,
+        b: B8,
+    }
+
+    let filled = Filled::new().with_a(0x2).with_b(0x341);
+    assert_eq!(filled, [0x12, 0x34]);
+    assert_ne!(filled, [0x12, 0x35]);
+
+    let unfilled = Unfilled::new().with_a(0x2).with_b(0x41);
+    assert_eq!(unfilled, [0x12, 0x04]);
+    assert_eq!(unfilled, [0x12, 0xF4]);
+    assert_ne!(unfilled, [0x12, 0x05]);
+}
+
+#[test]
+#[allow(
+    clippy::just_underscores_and_digits,
+    clippy::used_underscore_binding,
+    clippy::used_underscore_items
+)]
+fn field_trait() {
+    use modular_bitfield::Field;
+
+    #[bitfield(field_trait)]
+    struct Packet {
+        src_port: B4,
+        dst_port: B4,
+    }
+
+    #[bitfield(field_trait)]
+    struct Pair(u8, B8);
+
+    #[bitfield(field_trait, allow = "non_snake_case")]
+    struct Unnamed {
+        __: B4,
+        _0: B4,
+        r#type: B8,
+    }
+
+    fn read_any<S, K>(s: &S) -> <S as Field<K>>::Value
+    where
+        S: Field<K>,
+    {
+        s.read()
+    }
+
+    fn bump<S, K>(s: &mut S)
+    where
+        S: Field<K, Value = u8>,
+    {
+        let value = s.read();
+        s.write(value + 1);
+    }
+
+    let mut packet = Packet::new().with_src_port(3).with_dst_port(7);
+    assert_eq!(read_any::<_, packet_fields::SrcPort>(&packet), 3);
+    assert_eq!(read_any::<_, packet_fields::DstPort>(&packet), 7);
+    bump::<_, packet_fields::DstPort>(&mut packet);
+    assert_eq!(packet.dst_port(), 8);
+    assert_eq!(packet.src_port(), 3);
+
+    let mut pair = Pair::new().with_0(41).with_1(9);
+    bump::<_, pair_fields::Field0>(&mut pair);
+    assert_eq!(read_any::<_, pair_fields::Field0>(&pair), 42);
+    assert_eq!(read_any::<_, pair_fields::Field1>(&pair), 9);
+
+    let unnamed = Unnamed::new().with___(1).with__0(2).with_type(3);
+    assert_eq!(read_any::<_, unnamed_fields::Field0>(&unnamed), 1);
+    assert_eq!(read_any::<_, unnamed_fields::Field1>(&unnamed), 2);
+    assert_eq!(read_any::<_, unnamed_fields::Type>(&unnamed), 3);
+}
+
+// Without the `field_trait` parameter no `*_fields` module is generated and the name
+// remains available.
+#[test]
+fn field_trait_is_opt_in() {
+    #[allow(dead_code)]
+    mod packet_fields {}
+
+    #[bitfield]
+    struct Packet {
+        src_port: B4,
+        dst_port: B4,
+    }
+
+    assert_eq!(Packet::new().with_src_port(1).src_port(), 1);
+}
+
+#[test]
+fn restricted_visibility() {
+    // Compiles only if none of the generated items of the `pub(crate)`
+    // struct is declared as `pub`.
+    #[deny(unreachable_pub)]
+    mod restricted {
+        use modular_bitfield::prelude::*;
+
+        #[bitfield(filled = false)]
+        #[derive(Debug)]
+        pub(crate) struct Internal {
+            pub(crate) a: B4,
+            pub(crate) b: B3,
+        }
+    }
+
+    let internal = restricted::Internal::new().with_a(5).with_b(2);
+    assert_eq!(internal.into_bytes(), [0b0010_0101]);
+    let internal = restricted::Internal::from_bytes([0b0110_0011]).unwrap();
+    assert_eq!(internal.a(), 3);
+    assert_eq!(internal.b(), 6);
+}
+
+#[test]
+fn byte_aligned_field_refs() {
+    #[bitfield(ref_getters)]
+    struct Frame {
+        kind: B4,
+        flags: B4,
+        payload: B32,
+        crc: u16,
+    }
+
+    #[bitfield(ref_getters)]
+    struct Pair(u8, B16);
+
+    let frame = Frame::new()
+        .with_kind(0x1)
+        .with_flags(0x2)
+        .with_payload(0xDEAD_BEEF)
+        .with_crc(0x1234);
+    assert_eq!(frame.payload_ref(), &[0xEF, 0xBE, 0xAD, 0xDE]);
+    assert_eq!(frame.crc_ref(), &[0x34, 0x12]);
+    assert_eq!(frame.payload_ref()[..], frame.bytes[1..5]);
+
+    let pair = Pair::new().with_0(7).with_1(0xABCD);
+    assert_eq!(pair.get_0_ref(), &[7]);
+    assert_eq!(pair.get_1_ref(), &[0xCD, 0xAB]);
+}
+
+#[test]
+fn ref_getters_are_opt_in() {
+    // Compiles only if `x_ref` does not collide with the getter of the field `x_ref`.
+    #[bitfield]
+    struct Pair {
+        x: B8,
+        x_ref: B8,
+    }
+
+    let pair = Pair::new().with_x(1).with_x_ref(2);
+    assert_eq!(pair.x_ref(), 2);
+}
+
+#[test]
+fn keys() {
+    extern crate std;
+    use modular_bitfield::error::OutOfBounds;
+    use std::collections::HashMap;
+
+    #[bitfield(filled = false)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Coord {
+        x: B5,
+        y: B5,
+    }
+
+    #[bitfield]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Wide {
+        lo: u64,
+        hi: u64,
+    }
+
+    let mut names = HashMap::new();
+    let a = Coord::new().with_x(1).with_y(2);
+    let b = Coord::new().with_x(31).with_y(31);
+    names.insert(a.to_key(), "a");
+    names.insert(b.to_key(), "b");
+    assert_eq!(a.to_key(), 0b00010_00001);
+    assert_eq!(b.to_key(), 0x3FF);
+    for (key, name) in &names {
+        let coord = Coord::from_key(*key).unwrap();
+        assert_eq!(*name, if coord == a { "a" } else { "b" });
+    }
+    assert_eq!(Coord::from_key(0x400), Err(OutOfBounds));
+    assert_eq!(Coord::from_key(1 << 100), Err(OutOfBounds));
+
+    let wide = Wide::new().with_lo(u64::MAX).with_hi(1);
+    assert_eq!(wide.to_key(), (1 << 64) | u128::from(u64::MAX));
+    assert_eq!(Wide::from_key(wide.to_key()), Ok(wide));
+}
+
+#[test]
+fn try_set_all_restores_snapshot() {
+    use modular_bitfield::error::OutOfBounds;
+
+    #[bitfield]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Register {
+        enable: bool,
+        mode: B3,
+        counter: B20,
+        limit: B16,
+    }
+
+    let register = Register::new()
+        .with_enable(true)
+        .with_mode(5)
+        .with_counter(0xF_1234)
+        .with_limit(0xBEEF);
+    let snapshot = register.to_key();
+
+    let mut restored = Register::new();
+    assert_eq!(restored.try_set_all(snapshot), Ok(()));
+    assert_eq!(restored, register);
+    assert_eq!(restored.counter(), 0xF_1234);
+    assert_eq!(restored.limit(), 0xBEEF);
+
+    assert_eq!(restored.try_set_all(1 << 40), Err(OutOfBounds));
+    assert_eq!(restored, register);
+}
+
+#[test]
+fn set_masked() {
+    #[bitfield(set_masked)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Register {
+        enable: bool,
+        mode: B3,
+        counter: B12,
+        limit: B8,
+    }
+
+    let mut register = Register::new()
+        .with_enable(true)
+        .with_mode(5)
+        .with_counter(0xABC)
+        .with_limit(0x7F);
+    // Updates `mode` and `limit` at once.
+    let mask = (0b111 << 1) | (0xFF << 16);
+    register.set_masked(mask, (0b010 << 1) | (0x42 << 16) | (0xFFF << 4));
+    assert!(register.enable());
+    assert_eq!(register.mode(), 0b010);
+    assert_eq!(register.counter(), 0xABC);
+    assert_eq!(register.limit(), 0x42);
+
+    // Undefined bits of the mask are ignored.
+    register.set_masked(u128::MAX << 24, u128::MAX);
+    assert_eq!(register.to_key(), 0x42_ABC5);
+    register.set_masked(0, u128::MAX);
+    assert_eq!(register.to_key(), 0x42_ABC5);
+}
+
+#[test]
+fn set_masked_is_opt_in() {
+    // Compiles only if `set_masked` does not collide with the setter of `masked`.
+    #[bitfield]
+    struct Register {
+        masked: B4,
+        rest: B4,
+    }
+
+    let mut register = Register::new();
+    register.set_masked(0xA);
+    assert_eq!(register.masked(), 0xA);
+}
+
+#[test]
+fn bool_array_fields() {
+    #[bitfield(ref_getters)]
+    struct Flags {
+        mode: B4,
+        low: [bool; 3],
+        marker: bool,
+        all: [bool; 8],
+    }
+
+    let mut flags = Flags::new();
+    flags.set_low([true, false, true]);
+    flags.set_all([true, false, false, false, false, false, true, true]);
+    assert_eq!(flags.low(), [true, false, true]);
+    assert!(!flags.marker());
+    assert_eq!(
+        flags.all(),
+        [true, false, false, false, false, false, true, true]
+    );
+    // `all` starts and ends at byte boundaries.
+    assert_eq!(flags.all_ref(), &[0b1100_0001]);
+    assert_eq!(flags.into_bytes(), [0b0101_0000, 0b1100_0001]);
+    assert_eq!(<[bool; 8] as Specifier>::BITS, 8);
+    assert_eq!(
+        <[bool; 3] as Specifier>::from_bytes(0b1000),
+        Err(modular_bitfield::error::InvalidBitPattern::new(0b1000))
+    );
+
+    let wide = <[bool; 100] as Specifier>::into_bytes([true; 100]).unwrap();
+    assert_eq!(wide, (1 << 100) - 1);
+    assert_eq!(
+        <[bool; 100] as Specifier>::from_bytes(wide),
+        Ok([true; 100])
+    );
+}
+
+#[test]
+fn const_eq() {
+    #[bitfield]
+    struct Register {
+        enabled: bool,
+        mode: B3,
+        divider: B12,
+    }
+
+    #[bitfield(filled = false)]
+    struct Partial {
+        a: B4,
+        b: B5,
+    }
+
+    const EXPECTED: Register = Register::from_bytes([0x31, 0x12]);
+    const ACTUAL: Register = Register::from_bytes([0x31, 0x12]);
+    const _: () = assert!(EXPECTED.const_eq(&ACTUAL));
+    const _: () = assert!(!EXPECTED.const_eq(&Register::new()));
+    const _: () = assert!(Partial::ONES.const_eq(&Partial::ONES));
+    const _: () = assert!(!Partial::ONES.const_eq(&Partial::ZERO));
+
+    assert!(EXPECTED.const_eq(
+        &Register::new()
+            .with_enabled(true)
+            .with_mode(0)
+            .with_divider(0x123)
+    ));
+    assert!(!EXPECTED.const_eq(&Register::new().with_enabled(true).with_divider(0x124)));
+}
+
+#[test]
+fn contains_bit() {
+    #[bitfield]
+    struct Register {
+        enabled: bool,
+        mode: B3,
+        divider: B12,
+    }
+
+    const REGISTER: Register = Register::from_bytes([0b0000_1001, 0b1000_0000]);
+    const _: () = assert!(REGISTER.contains_bit(0));
+    const _: () = assert!(!REGISTER.contains_bit(1));
+    const _: () = assert!(REGISTER.contains_bit(3));
+    const _: () = assert!(!REGISTER.contains_bit(8));
+    const _: () = assert!(REGISTER.contains_bit(15));
+
+    let register = Register::new().with_divider(1);
+    assert!(register.contains_bit(4));
+    assert!((0..16)
+        .filter(|n| *n != 4)
+        .all(|n| !register.contains_bit(n)));
+}
+
+#[test]
+fn overwrite_from() {
+    #[bitfield]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Register {
+        enable: bool,
+        mode: B3,
+        counter: B12,
+        limit: B8,
+    }
+
+    let mut register = Register::new()
+        .with_enable(true)
+        .with_mode(5)
+        .with_counter(0xABC)
+        .with_limit(0x7F);
+    let value = Register::new()
+        .with_mode(0b010)
+        .with_counter(0x123)
+        .with_limit(0x42);
+    let mask = Register::new().with_mode(0b111).with_limit(0x0F);
+    register.overwrite_from(&value, &mask);
+    assert!(register.enable());
+    assert_eq!(register.mode(), 0b010);
+    assert_eq!(register.counter(), 0xABC);
+    assert_eq!(register.limit(), 0x72);
+
+    // An empty mask leaves all bits untouched while a full mask copies all of them.
+    let before = register;
+    register.overwrite_from(&value, &Register::ZERO);
+    assert_eq!(register, before);
+    register.overwrite_from(&value, &Register::ONES);
+    assert_eq!(register, value);
+}
+
+#[test]
+fn padding_fields() {
+    #[bitfield]
+    #[derive(Debug)]
+    struct Padded {
+        a: B4,
+        #[padding(4)]
+        _reserved: (),
+        b: u8,
+        #[padding(7)]
+        _tail: (),
+        c: bool,
+    }
+
+    assert_eq!(core::mem::size_of::<Padded>(), 3);
+    let padded = Padded::new().with_a(0xF).with_b(0xAB).with_c(true);
+    assert_eq!(padded.into_bytes(), [0x0F, 0xAB, 0x80]);
+    // Padding bits are neither read nor written by accessors.
+    let padded = Padded::from_bytes([0xFF, 0x00, 0x7F]);
+    assert_eq!(padded.a(), 0xF);
+    assert_eq!(padded.b(), 0);
+    assert!(!padded.c());
+}
+
+#[test]
+fn bit_set_fields() {
+    use modular_bitfield::BitSet;
+
+    #[bitfield]
+    struct Interrupts {
+        enabled: bool,
+        flags: BitSet<12>,
+        priority: B3,
+    }
+
+    let mut interrupts = Interrupts::new().with_enabled(true).with_priority(0b101);
+    let mut flags = interrupts.flags();
+    assert_eq!(flags, BitSet::new());
+    flags.set(0, true);
+    flags.set(5, true);
+    flags.set(11, true);
+    interrupts.set_flags(flags);
+
+    let flags = interrupts.flags();
+    assert_eq!(flags.count_ones(), 3);
+    for index in 0..12 {
+        assert_eq!(
+            flags.get(index),
+            matches!(index, 0 | 5 | 11),
+            "flag {index}"
+        );
+    }
+    assert!(interrupts.enabled());
+    assert_eq!(interrupts.priority(), 0b101);
+    assert_eq!(interrupts.into_bytes(), [0b0100_0011, 0b1011_0000]);
+
+    let mut flags = BitSet::from([true; 12]);
+    flags.set(11, false);
+    let interrupts = Interrupts::new().with_flags(flags);
+    assert_eq!(interrupts.flags().into_array()[..11], [true; 11]);
+    assert!(!interrupts.flags().get(11));
+    assert_eq!(<BitSet<12> as Specifier>::BITS, 12);
+}
+
+#[test]
+fn group_accessors() {
+    #[bitfield]
+    struct Timestamp {
+        #[group(Date)]
+        year: B7,
+        #[group(Date)]
+        month: B4,
+        #[group(Date)]
+        day: B5,
+        #[group(Time)]
+        hour: B5,
+        #[group(Time)]
+        minute: B6,
+        #[skip]
+        __: B5,
+    }
+
+    let mut timestamp = Timestamp::new().with_year(24).with_hour(13);
+    let date = timestamp.date();
+    assert_eq!((date.year, date.month, date.day), (24, 0, 0));
+
+    timestamp.set_date(Date {
+        year: 25,
+        month: 12,
+        day: 31,
+    });
+    assert_eq!(timestamp.year(), 25);
+    assert_eq!(timestamp.month(), 12);
+    assert_eq!(timestamp.day(), 31);
+    assert_eq!(timestamp.hour(), 13);
+
+    let timestamp = timestamp.with_time(Time {
+        hour: 23,
+        minute: 59,
+    });
+    let date = timestamp.date();
+    let time = timestamp.time();
+    assert_eq!((date.year, date.month, date.day), (25, 12, 31));
+    assert_eq!((time.hour, time.minute), (23, 59));
+    let round_trip = Timestamp::new().with_date(timestamp.date()).with_time(time);
+    assert_eq!(round_trip.into_bytes(), timestamp.into_bytes());
+}
+
+#[test]
+fn as_ref_and_as_mut_bytes() {
+    #[bitfield]
+    struct Packet {
+        kind: B4,
+        len: B12,
+    }
+
+    fn checksum(bytes: impl AsRef<[u8]>) -> u8 {
+        bytes.as_ref().iter().fold(0, |acc, byte| acc ^ byte)
+    }
+
+    let mut packet = Packet::new().with_kind(0x3).with_len(0xAB1);
+    let bytes: &[u8] = packet.as_ref();
+    assert_eq!(bytes, [0x13, 0xAB]);
+
+    let bytes: &mut [u8] = packet.as_mut();
+    bytes[1] = 0xCD;
+    assert_eq!(packet.len(), 0xCD1);
+    assert_eq!(checksum(packet), 0x13 ^ 0xCD);
+}
+
+#[test]
+fn cfg_attr_derives() {
+    extern crate alloc;
+    use alloc::format;
+
+    #[bitfield]
+    #[cfg_attr(test, derive(Debug, Clone, Copy, PartialEq, Eq))]
+    #[cfg_attr(not(test), derive(NotADerive))]
+    struct Enabled {
+        a: B4,
+        b: B4,
+    }
+
+    #[bitfield(bits = 4)]
+    #[cfg_attr(test, derive(Specifier))]
+    struct Nested {
+        value: B4,
+    }
+
+    #[bitfield]
+    #[cfg_attr(not(test), derive(Debug))]
+    #[cfg_attr(not(test), repr(u32))]
+    struct Disabled {
+        nested: Nested,
+        rest: B4,
+    }
+
+    let enabled = Enabled::new().with_a(1).with_b(2);
+    let copied = enabled;
+    assert_eq!(enabled, copied);
+    assert_eq!(format!("{enabled:?}"), "Enabled { a: 1, b: 2 }");
+
+    let disabled = Disabled::new().with_nested(Nested::new().with_value(3));
+    assert_eq!(disabled.into_bytes(), [3]);
+}
+
+#[test]
+fn checked_into_bytes() {
+    use modular_bitfield::error::FieldError;
+
+    #[derive(Specifier, Debug, PartialEq)]
+    #[bits = 2]
+    enum Mode {
+        A = 0,
+        B = 1,
+        C = 2,
+    }
+
+    #[bitfield]
+    struct Frame {
+        flag: bool,
+        mode: Mode,
+        value: B5,
+    }
+
+    #[bitfield]
+    struct Pair(B4, Mode, B2);
+
+    let frame = Frame::new()
+        .with_flag(true)
+        .with_mode(Mode::C)
+        .with_value(9);
+    assert_eq!(frame.checked_into_bytes(), Ok([0b0100_1101]));
+
+    // Bit pattern `0b11` is undefined for `Mode`.
+    let frame = Frame::from_bytes([0b0000_0110]);
+    assert_eq!(frame.checked_into_bytes(), Err(FieldError::new("mode")));
+    assert_eq!(
+        Pair::from_bytes([0b0011_0000]).checked_into_bytes(),
+        Err(FieldError::new("1"))
+    );
+}
+
+#[test]
+fn signed_accessors() {
+    use modular_bitfield::error::OutOfBounds;
+
+    #[bitfield]
+    struct Sample {
+        #[signed]
+        delta: B4,
+        flag: bool,
+        #[signed]
+        wide: B3,
+    }
+
+    #[bitfield]
+    struct Tuple(#[signed] B4, #[signed] B4);
+
+    // Without `#[signed]` no signed accessors are generated and the names remain available.
+    #[bitfield]
+    struct Unsigned {
+        x: B4,
+        x_signed: B4,
+    }
+
+    let mut sample = Sample::new();
+    sample.set_delta_signed(-3);
+    assert_eq!(sample.delta(), 0b1101);
+    assert_eq!(sample.delta_signed(), -3);
+    assert!(!sample.flag());
+
+    sample.set_delta_signed(7);
+    assert_eq!(sample.delta_signed(), 7);
+    sample.set_delta_signed(-8);
+    assert_eq!(sample.delta_signed(), -8);
+    assert_eq!(sample.set_delta_signed_checked(8), Err(OutOfBounds));
+    assert_eq!(sample.set_delta_signed_checked(-9), Err(OutOfBounds));
+    assert_eq!(sample.delta_signed(), -8);
+
+    sample.set_wide(0b111);
+    assert_eq!(sample.wide_signed(), -1);
+
+    let mut tuple = Tuple::new();
+    tuple.set_1_signed(-1);
+    assert_eq!(tuple.get_1(), 0b1111);
+    assert_eq!(tuple.get_1_signed(), -1);
+    assert_eq!(tuple.get_0_signed(), 0);
+
+    let unsigned = Unsigned::new().with_x(0b1111).with_x_signed(1);
+    assert_eq!(unsigned.x(), 0b1111);
+    assert_eq!(unsigned.x_signed(), 1);
+}
+
+#[test]
+fn shadowed_builtin_specifier_name() {
+    mod shadow {
+        use modular_bitfield::prelude::*;
+
+        #[derive(Specifier, Debug, PartialEq)]
+        pub enum B2 {
+            Zero,
+            One,
+            Two,
+            Three,
+        }
+    }
+
+    #[bitfield]
+    struct Register {
+        mode: shadow::B2,
+        rest: B6,
+    }
+
+    // The getter returns the user defined specifier instead of the raw bits of `B2`.
+    let register = Register::new().with_mode(shadow::B2::Two);
+    let mode: shadow::B2 = register.mode();
+    assert_eq!(mode, shadow::B2::Two);
+    assert_eq!(register.into_bytes(), [0b10]);
+}
+
+#[test]
+fn const_getters() {
+    #[bitfield]
+    struct Header {
+        is_last: bool,
+        kind: B3,
+        length: B8,
+        wide: B100,
+        tail: u8,
+        id: B8,
+    }
+
+    const HEADER: Header = Header::from_bytes([
+        0b1011_0101,
+        0x7F,
+        0xFF,
+        0xFF,
+        0xFF,
+        0xFF,
+        0xFF,
+        0xFF,
+        0xFF,
+        0xFF,
+        0xFF,
+        0xFF,
+        0xFF,
+        0xAF,
+        0xE5,
+        0xBC,
+    ]);
+    const KIND: u8 = HEADER.kind();
+    const LENGTH: u8 = HEADER.length();
+    const WIDE: u128 = HEADER.wide();
+    const TAIL: u8 = HEADER.tail();
+    const ID: u8 = HEADER.id();
+
+    const _: () = assert!(HEADER.is_last());
+    assert_eq!(KIND, 0b010);
+    assert_eq!(LENGTH, 0xFB);
+    assert_eq!(WIDE, 0xA_FFFF_FFFF_FFFF_FFFF_FFFF_FFF7);
+    assert_eq!(TAIL, 0xE5);
+    assert_eq!(ID, 0xBC);
+    assert_eq!(HEADER.length(), HEADER.length_or_err().unwrap());
+    assert_eq!(HEADER.wide(), HEADER.wide_or_err().unwrap());
+    assert_eq!(HEADER.tail(), HEADER.tail_or_err().unwrap());
+}
+
+#[test]
+fn cfg_fields() {
+    #[bitfield(filled = false)]
+    #[derive(Debug)]
+    struct Config {
+        a: B4,
+        #[cfg(any())]
+        absent: B8,
+        #[cfg(not(any()))]
+        present: B8,
+        c: B3,
+    }
+
+    #[bitfield(filled = false)]
+    struct Featured {
+        flags: B4,
+        #[cfg(feature = "std")]
+        extended: B16,
+        tail: B3,
+    }
+
+    assert_eq!(core::mem::size_of::<Config>(), 2);
+    let config = Config::new().with_present(0xAB).with_c(5);
+    assert_eq!(config.into_bytes(), [0xB0, 0x5A]);
+
+    let expected_size = if cfg!(feature = "std") { 3 } else { 1 };
+    assert_eq!(core::mem::size_of::<Featured>(), expected_size);
+    let featured = Featured::new().with_tail(0b101);
+    assert_eq!(featured.tail(), 0b101);
+    #[cfg(not(feature = "std"))]
+    assert_eq!(featured.into_bytes(), [0b101_0000]);
+    #[cfg(feature = "std")]
+    assert_eq!(featured.into_bytes(), [0, 0, 0b101_0000]);
+}
+
+#[test]
+fn get_all() {
+    use modular_bitfield::error::FieldError;
+
+    #[derive(Specifier, Debug, PartialEq)]
+    #[bits = 2]
+    enum Mode {
+        A = 0,
+        B = 1,
+        C = 2,
+    }
+
+    #[bitfield]
+    struct Frame {
+        flag: bool,
+        mode: Mode,
+        #[skip]
+        reserved: B1,
+        value: B4,
+    }
+
+    let frame = Frame::new()
+        .with_flag(true)
+        .with_mode(Mode::B)
+        .with_value(9);
+    let (flag, mode, value) = frame.get_all();
+    assert!(flag);
+    assert_eq!(mode, Mode::B);
+    assert_eq!(value, 9);
+    assert_eq!(frame.try_get_all(), Ok((true, Mode::B, 9)));
+
+    // Bit pattern `0b11` is undefined for `Mode`.
+    let frame = Frame::from_bytes([0b0000_0110]);
+    assert_eq!(frame.try_get_all(), Err(FieldError::new("mode")));
+    assert_eq!(Mode::C, Frame::new().with_mode(Mode::C).get_all().1);
+}
+
+#[test]
+fn from_fields() {
+    use modular_bitfield::error::OutOfBounds;
+
+    #[derive(Specifier, Debug, PartialEq)]
+    #[bits = 2]
+    enum Mode {
+        A = 0,
+        B = 1,
+        C = 2,
+    }
+
+    #[bitfield]
+    struct Header {
+        flag: bool,
+        #[skip]
+        reserved: B3,
+        kind: B4,
+        len: u8,
+    }
+
+    #[bitfield]
+    struct Frame {
+        flag: bool,
+        mode: Mode,
+        value: B5,
+    }
+
+    #[bitfield]
+    struct Pair(B4, B4);
+
+    const HEADER: Header = Header::from_fields(true, 0xA, 0x42);
+    const PAIR: Pair = Pair::from_fields(0x3, 0xC);
+
+    assert!(HEADER.flag());
+    assert_eq!(HEADER.kind(), 0xA);
+    assert_eq!(HEADER.len(), 0x42);
+    assert_eq!(HEADER.into_bytes(), [0xA1, 0x42]);
+
+    assert_eq!(PAIR.into_bytes(), [0xC3]);
+
+    let frame = Frame::from_fields(true, Mode::C, 9).unwrap();
+    assert_eq!(frame.mode(), Mode::C);
+    assert_eq!(frame.into_bytes(), [0b0100_1101]);
+    assert_eq!(
+        Frame::from_fields(true, Mode::A, 32).err(),
+        Some(OutOfBounds)
+    );
+}
+
+#[test]
+#[should_panic(expected = "value out of bounds for field Header.kind")]
+fn from_fields_out_of_bounds() {
+    #[bitfield]
+    struct Header {
+        flag: bool,
+        kind: B7,
+    }
+
+    let _ = Header::from_fields(true, 0x80);
+}
+
+#[test]
+fn from_fields_with_shadowed_builtin_name() {
+    mod shadow {
+        use modular_bitfield::prelude::*;
+
+        #[derive(Specifier, Debug, PartialEq)]
+        pub enum B2 {
+            Zero,
+            One,
+            Two,
+            Three,
+        }
+    }
+
+    #[bitfield]
+    struct Register {
+        mode: shadow::B2,
+        rest: B6,
+    }
+
+    // The values are converted through `Specifier::into_bytes` of the user defined `B2`.
+    let register = Register::from_fields(shadow::B2::Three, 0b10_1010).unwrap();
+    assert_eq!(register.mode(), shadow::B2::Three);
+    assert_eq!(register.into_bytes(), [0b1010_1011]);
+}
+
+#[test]
+fn from_iter_bools() {
+    extern crate alloc;
+    use alloc::{vec, vec::Vec};
+
+    #[bitfield(filled = false)]
+    struct Serial {
+        start: bool,
+        data: B8,
+        parity: bool,
+    }
+
+    let bits: Vec<bool> = vec![
+        true, // start
+        true, false, true, true, false, false, true, false, // data: 0b0100_1101
+        true,  // parity
+    ];
+    let serial = bits.into_iter().collect::<Serial>();
+    assert!(serial.start());
+    assert_eq!(serial.data(), 0b0100_1101);
+    assert!(serial.parity());
+    assert_eq!(serial.into_bytes(), [0b1001_1011, 0b0000_0010]);
+
+    // Fewer bits leave the remaining fields zeroed.
+    let serial = [true, true].into_iter().collect::<Serial>();
+    assert_eq!(serial.into_bytes(), [0b0000_0011, 0]);
+}
+
+#[test]
+#[should_panic(expected = "iterator yields more bits than the fields of Serial can hold")]
+fn from_iter_too_many_bools() {
+    #[bitfield(filled = false)]
+    struct Serial {
+        start: bool,
+        data: B8,
+    }
+
+    let _ = core::iter::repeat(true).take(10).collect::<Serial>();
+}
+
+#[test]
+fn set_raw_bits() {
+    use modular_bitfield::error::InvalidBitPattern;
+
+    #[derive(Specifier, Debug, PartialEq)]
+    #[bits = 2]
+    enum Mode {
+        A = 0,
+        B = 1,
+        C = 2,
+    }
+
+    #[bitfield(raw_setters)]
+    struct Frame {
+        mode: Mode,
+        value: B6,
+    }
+
+    let mut frame = Frame::new();
+    assert_eq!(frame.set_mode_bits(0b10), Ok(()));
+    assert_eq!(frame.mode(), Mode::C);
+
+    // Bit pattern `0b11` is undefined for `Mode` and too wide for its 2 bits.
+    assert_eq!(frame.set_mode_bits(0b11), Err(InvalidBitPattern::new(0b11)));
+    assert_eq!(
+        frame.set_mode_bits(0b100),
+        Err(InvalidBitPattern::new(0b100))
+    );
+    assert_eq!(frame.mode(), Mode::C);
+
+    assert_eq!(frame.set_value_bits(0x3F), Ok(()));
+    assert_eq!(
+        frame.set_value_bits(0x40),
+        Err(InvalidBitPattern::new(0x40))
+    );
+    assert_eq!(frame.into_bytes(), [0xFE]);
+}
+
+#[test]
+fn raw_setters_are_opt_in() {
+    // Compiles only if `set_mode_bits` does not collide with the setter of `mode_bits`.
+    #[bitfield]
+    struct Frame {
+        mode: B2,
+        mode_bits: B6,
+    }
+
+    let mut frame = Frame::new().with_mode(1);
+    frame.set_mode_bits(0x3F);
+    assert_eq!(frame.mode(), 1);
+    assert_eq!(frame.mode_bits(), 0x3F);
+}
+
+#[test]
+fn expect_value_attribute() {
+    #[derive(Specifier, Debug, PartialEq)]
+    #[bits = 2]
+    enum Kind {
+        Data = 0,
+        Ack = 1,
+        Nack = 2,
+    }
+
+    #[bitfield]
+    struct Header {
+        #[expect_value = 2]
+        version: B4,
+        #[expect_value = Kind::Ack]
+        kind: Kind,
+        #[skip(setters)]
+        #[expect_value = 0]
+        reserved: B2,
+        len: u8,
+    }
+
+    let header = Header::new()
+        .with_version(2)
+        .with_kind(Kind::Ack)
+        .with_len(42);
+    assert!(header.is_valid_version());
+    assert!(header.is_valid_kind());
+    assert!(header.is_valid_reserved());
+    assert!(header.is_well_formed());
+
+    let header = Header::from_bytes([0x13, 42]);
+    assert!(!header.is_valid_version());
+    assert!(!header.is_well_formed());
+
+    // Bit pattern `0b11` is undefined for `Kind` and thus never the expected value.
+    let header = Header::from_bytes([0x32, 42]);
+    assert!(!header.is_valid_kind());
+    assert!(!header.is_well_formed());
+
+    let header = Header::from_bytes([0x92, 42]);
+    assert!(header.is_valid_version() && header.is_valid_kind());
+    assert!(!header.is_valid_reserved());
+    assert!(!header.is_well_formed());
+}
+
+#[test]
+fn parse_prefix() {
+    use modular_bitfield::error::OutOfBounds;
+
+    #[bitfield]
+    #[derive(Debug)]
+    struct Header {
+        kind: B4,
+        len: B12,
+    }
+
+    #[bitfield(filled = false)]
+    #[derive(Debug)]
+    struct Flags {
+        a: bool,
+        b: bool,
+    }
+
+    let buffer = [0x21, 0x43, 0x65, 0x87, 0x02, 0xFF];
+    let (first, used) = Header::parse_prefix(&buffer).unwrap();
+    assert_eq!((first.kind(), first.len(), used), (0x1, 0x432, 2));
+    let (second, used) = Header::parse_prefix(&buffer[used..]).unwrap();
+    assert_eq!((second.kind(), second.len(), used), (0x5, 0x876, 2));
+    let (flags, used) = Flags::parse_prefix(&buffer[4..]).unwrap();
+    assert_eq!((flags.a(), flags.b(), used), (false, true, 1));
+
+    assert_eq!(Header::parse_prefix(&buffer[5..]).unwrap_err(), OutOfBounds);
+    assert_eq!(Flags::parse_prefix(&buffer[5..]).unwrap_err(), OutOfBounds);
+    assert_eq!(Flags::parse_prefix(&[]).unwrap_err(), OutOfBounds);
+}
+
+#[test]
+fn masked_specifier() {
+    use modular_bitfield::{
+        error::{InvalidBitPattern, OutOfBounds},
+        Masked,
+    };
+
+    #[derive(Specifier, Debug, PartialEq)]
+    #[bits = 4]
+    enum Mode {
+        Off = 0b0000,
+        Low = 0b0010,
+        High = 0b1000,
+        Turbo = 0b1010,
+    }
+
+    #[bitfield]
+    struct Control {
+        level: Masked<B8, 0b1111_0000>,
+        mode: Masked<Mode, 0b1010>,
+        enabled: Masked<bool, 0b1>,
+        locked: Masked<bool, 0b0>,
+        rest: B2,
+    }
+
+    let mut control = Control::new();
+    control.set_level(0b1010_0000);
+    control.set_mode(Mode::Turbo);
+    control.set_enabled(true);
+    assert_eq!(control.level(), 0b1010_0000);
+    assert_eq!(control.mode(), Mode::Turbo);
+    assert!(control.enabled());
+    assert!(!control.locked());
+    assert_eq!(control.set_level_checked(0b0000_1000), Err(OutOfBounds));
+    assert_eq!(control.set_locked_checked(true), Err(OutOfBounds));
+    assert_eq!(control.level(), 0b1010_0000);
+
+    let control = Control::from_bytes([0b0000_0001, 0b0010_0100]);
+    assert_eq!(
+        control.level_or_err(),
+        Err(InvalidBitPattern::new(0b0000_0001))
+    );
+    assert_eq!(control.mode_or_err(), Err(InvalidBitPattern::new(0b0100)));
+    assert_eq!(control.locked_or_err(), Err(InvalidBitPattern::new(1)));
+}
+
+#[test]
+fn bit_reverse() {
+    #[derive(Specifier, Debug, PartialEq)]
+    #[bits = 3]
+    enum Mode {
+        Idle = 0b000,
+        Read = 0b001,
+        Write = 0b011,
+    }
+
+    #[bitfield(raw_setters)]
+    struct Frame {
+        start: bool,
+        #[bit_reverse]
+        command: B4,
+        #[bit_reverse]
+        mode: Mode,
+        #[bit_reverse]
+        address: B12,
+        rest: B4,
+    }
+
+    let mut frame = Frame::new()
+        .with_start(true)
+        .with_command(0b0011)
+        .with_mode(Mode::Read);
+    frame.set_address(0x00F);
+    assert_eq!(frame.command(), 0b0011);
+    assert_eq!(frame.mode(), Mode::Read);
+    assert_eq!(frame.address(), 0x00F);
+    // command `0011` is stored as `1100` at bits 1..5, mode `001` as `100` at bits 5..8
+    // and address `0000_0000_1111` as `1111_0000_0000` at bits 8..20.
+    assert_eq!(frame.into_bytes(), [0b1001_1001, 0b0000_0000, 0b0000_1111]);
+
+    let mut frame = Frame::from_bytes([0b1100_0000, 0b0000_0001, 0b0000_0000]);
+    assert_eq!(frame.command(), 0);
+    assert_eq!(frame.mode(), Mode::Write);
+    assert_eq!(frame.address(), 0x800);
+    assert_eq!(
+        frame.set_command_checked(16),
+        Err(modular_bitfield::error::OutOfBounds)
+    );
+    assert!(frame.set_mode_bits(0b010).is_err());
+    assert_eq!(frame.set_mode_bits(0b001), Ok(()));
+    assert_eq!(frame.into_bytes()[0], 0b1000_0000);
+}
+
+#[test]
+fn bit_reverse_has_no_ref_getter() {
+    #[bitfield(ref_getters)]
+    struct Frame {
+        #[bit_reverse]
+        data: B8,
+        rest: B8,
+    }
+
+    // Compiles only if no conflicting `data_ref` getter is generated for the reversed field.
+    impl Frame {
+        fn data_ref(&self) -> u8 {
+            self.data()
+        }
+    }
+
+    let frame = Frame::new().with_data(0b0000_0011);
+    assert_eq!(frame.data_ref(), 0b0000_0011);
+    assert_eq!(frame.rest_ref(), &[0]);
+    assert_eq!(frame.into_bytes(), [0b1100_0000, 0]);
+}
+
+#[test]
+fn qualified_and_aliased_specifier_paths() {
+    mod other {
+        pub mod specifiers {
+            pub use modular_bitfield::specifiers::B12;
+
+            pub type Nibble = modular_bitfield::specifiers::B4;
+        }
+    }
+
+    #[bitfield]
+    struct Header {
+        kind: other::specifiers::Nibble,
+        #[signed]
+        len: ::modular_bitfield::specifiers::B12,
+        addr: other::specifiers::B12,
+        flags: <B4 as modular_bitfield::Specifier>::InOut,
+        tail: other::specifiers::Nibble,
+    }
+
+    let header = Header::new()
+        .with_kind(0xA)
+        .with_len(0x123)
+        .with_addr(0xFFF)
+        .with_flags(0x42);
+    assert_eq!(header.kind(), 0xA);
+    assert_eq!(header.len(), 0x123);
+    assert_eq!(header.addr(), 0xFFF);
+    assert_eq!(header.flags(), 0x42);
+    assert_eq!(header.len_signed(), 0x123);
+    assert_eq!(header.into_bytes(), [0x3A, 0x12, 0xFF, 0x2F, 0x04]);
+}
@@ -0,0 +1,58 @@
+//! Tests for the `Specifier` implementations of IP addresses of the `std` feature.
+
+extern crate std;
+
+use modular_bitfield::prelude::*;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+#[bitfield]
+#[derive(Debug, PartialEq)]
+pub struct Ipv4Header {
+    version: B4,
+    ihl: B4,
+    ttl: u8,
+    src: Ipv4Addr,
+    dst: Ipv4Addr,
+}
+
+#[bitfield]
+#[derive(Debug, PartialEq)]
+pub struct Ipv6Route {
+    prefix_len: u8,
+    addr: Ipv6Addr,
+}
+
+#[test]
+fn ipv4_addr() {
+    let src = Ipv4Addr::new(192, 168, 0, 1);
+    let dst = Ipv4Addr::new(10, 0, 0, 254);
+    let mut header = Ipv4Header::new()
+        .with_version(4)
+        .with_ihl(5)
+        .with_ttl(64)
+        .with_src(src);
+    header.set_dst(dst);
+    assert_eq!(header.version(), 4);
+    assert_eq!(header.ihl(), 5);
+    assert_eq!(header.ttl(), 64);
+    assert_eq!(header.src(), src);
+    assert_eq!(header.dst(), dst);
+    assert_eq!(header.src_or_err(), Ok(src));
+
+    // The address is stored as the `u32` of its octets in network byte order.
+    let bytes = header.into_bytes();
+    assert_eq!(&bytes[2..6], &u32::from(src).to_le_bytes());
+    assert_eq!(Ipv4Header::from_bytes(bytes).dst(), dst);
+}
+
+#[test]
+fn ipv6_addr() {
+    let addr = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0xff00, 0x42);
+    let route = Ipv6Route::new().with_prefix_len(64).with_addr(addr);
+    assert_eq!(route.prefix_len(), 64);
+    assert_eq!(route.addr(), addr);
+
+    let bytes = route.into_bytes();
+    assert_eq!(&bytes[1..], &u128::from(addr).to_le_bytes());
+    assert_eq!(Ipv6Route::from_bytes(bytes).addr(), addr);
+}
@@ -0,0 +1,42 @@
+//! Tests for `pad_to_pow2` #[bitfield] parameter
+
+use modular_bitfield::prelude::*;
+
+#[test]
+fn pad_to_pow2() {
+    use modular_bitfield::error::OutOfBounds;
+
+    #[bitfield(pad_to_pow2)]
+    #[derive(Specifier, Debug, PartialEq)]
+    struct Descriptor {
+        kind: B4,
+        length: B16,
+    }
+
+    #[bitfield(pad_to_pow2)]
+    struct Exact {
+        value: u16,
+    }
+
+    assert_eq!(Descriptor::BYTES, 4);
+    assert_eq!(core::mem::size_of::<Descriptor>(), 4);
+    assert_eq!(<Descriptor as Specifier>::BITS, 32);
+    assert_eq!(Exact::BYTES, 2);
+
+    let descriptor = Descriptor::new().with_kind(0xA).with_length(0xFFFF);
+    assert_eq!(
+        Descriptor::from_bytes([0xFA, 0xFF, 0x0F, 0x00]),
+        Ok(descriptor)
+    );
+    assert_eq!(
+        Descriptor::from_bytes([0xFA, 0xFF, 0x0F, 0x00]).map(Descriptor::into_bytes),
+        Ok([0xFA, 0xFF, 0x0F, 0x00])
+    );
+    // The padding bits and bytes must always be zero.
+    assert_eq!(Descriptor::from_bytes([0, 0, 0x10, 0]), Err(OutOfBounds));
+    assert_eq!(Descriptor::from_bytes([0, 0, 0, 0x01]), Err(OutOfBounds));
+    assert_eq!(
+        Exact::from_bytes([0x34, 0x12]).map(|exact| exact.value()),
+        Ok(0x1234)
+    );
+}
@@ -0,0 +1,39 @@
+//! Tests for `prop_test` #[bitfield] parameter
+
+use modular_bitfield::prelude::*;
+
+#[test]
+fn prop_test_param() {
+    #[derive(Specifier, Debug, PartialEq)]
+    #[bits = 2]
+    enum Mode {
+        Off,
+        On,
+        Auto,
+    }
+
+    #[bitfield(prop_test)]
+    struct Register {
+        enabled: bool,
+        mode: Mode,
+        #[bit_reverse]
+        level: B5,
+        #[skip]
+        __: B4,
+        counter: B20,
+    }
+
+    #[bitfield(prop_test, filled = false, fallible = true)]
+    struct Partial(B3, u8, #[skip(setters)] B2);
+
+    #[bitfield(prop_test, rename_accessors = "snake")]
+    #[allow(non_snake_case)]
+    struct Wide {
+        lowBits: u64,
+        highBits: B64,
+    }
+
+    Register::check_round_trip();
+    Partial::check_round_trip();
+    Wide::check_round_trip();
+}
@@ -0,0 +1,40 @@
+//! Tests for `rename_accessors` #[bitfield] parameter
+
+use modular_bitfield::prelude::*;
+
+#[test]
+fn rename_accessors() {
+    use modular_bitfield::error::OutOfBounds;
+
+    #[bitfield(rename_accessors = "snake")]
+    #[allow(non_snake_case)]
+    struct Header {
+        messageType: B4,
+        isLast: bool,
+        r#type: B3,
+    }
+
+    let mut header = Header::new().with_message_type(9).with_type(5);
+    assert_eq!(header.message_type(), 9);
+    assert_eq!(header.message_type_or_err(), Ok(9));
+    assert!(!header.is_last());
+    header.set_is_last(true);
+    header.set_message_type(3);
+    assert!(header.is_last());
+    assert_eq!(header.message_type(), 3);
+    assert_eq!(header.r#type(), 5);
+    assert_eq!(header.set_message_type_checked(16), Err(OutOfBounds));
+}
+
+#[test]
+fn rename_accessors_transparent() {
+    #[bitfield(rename_accessors = "snake", transparent = true)]
+    #[allow(non_snake_case)]
+    struct Wrapper {
+        innerValue: u8,
+    }
+
+    let wrapper = Wrapper::try_from(42).unwrap();
+    assert_eq!(wrapper.inner_value(), 42);
+    assert_eq!(u8::from(wrapper), 42);
+}
@@ -89,6 +89,24 @@ fn multiple_valid_reprs_2() {
     }
 }
 
+#[test]
+fn integer_and_byte_array_views() {
+    #[bitfield]
+    #[repr(u16)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Word {
+        low: B4,
+        high: B12,
+    }
+
+    let word = Word::from(0xABCD_u16);
+    assert_eq!(<[u8; 2]>::from(Word::from(0xABCD_u16)), [0xCD, 0xAB]);
+    assert_eq!(Word::from([0xCD, 0xAB]), word);
+    assert_eq!(u16::from(Word::from([0xCD, 0xAB])), 0xABCD);
+    assert_eq!(word.low(), 0xD);
+    assert_eq!(word.high(), 0xABC);
+}
+
 #[test]
 fn valid_cond_use() {
     #[bitfield]
@@ -137,3 +155,89 @@ valid_use!(
     (valid_use_u64, u64, B63),
     (valid_use_u128, u128, B127)
 );
+
+#[test]
+fn single_enum_field() {
+    #[derive(Specifier, Debug, Clone, Copy, PartialEq, Eq)]
+    #[bits = 8]
+    pub enum StatusKind {
+        Idle = 0x00,
+        Busy = 0x01,
+        Fault = 0x7F,
+        Reset = 0xFF,
+    }
+
+    #[bitfield]
+    #[repr(u8)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Status {
+        kind: StatusKind,
+    }
+
+    for kind in [
+        StatusKind::Idle,
+        StatusKind::Busy,
+        StatusKind::Fault,
+        StatusKind::Reset,
+    ] {
+        let status = Status::new().with_kind(kind);
+        let raw = u8::from(status);
+        assert_eq!(raw, <StatusKind as Specifier>::into_bytes(kind).unwrap());
+        assert_eq!(raw, kind as u8);
+        assert_eq!(Status::from(raw).kind(), kind);
+    }
+    assert!(Status::from(0x02).kind_or_err().is_err());
+}
+
+#[test]
+fn transparent_by_default() {
+    #[bitfield]
+    #[derive(Debug, Clone, Copy)]
+    pub struct Word {
+        value: B32,
+    }
+
+    #[bitfield]
+    #[repr(align(4))]
+    pub struct Aligned {
+        low: B4,
+        high: B4,
+    }
+
+    assert_eq!(core::mem::size_of::<Word>(), 4);
+    assert_eq!(core::mem::align_of::<Word>(), 1);
+    // SAFETY: `Word` is `#[repr(transparent)]` over `[u8; 4]`.
+    let word = unsafe { core::mem::transmute::<[u8; 4], Word>([0x78, 0x56, 0x34, 0x12]) };
+    assert_eq!(word.value(), 0x1234_5678);
+
+    // Other representations are retained instead.
+    assert_eq!(core::mem::size_of::<Aligned>(), 4);
+    assert_eq!(core::mem::align_of::<Aligned>(), 4);
+    assert_eq!(
+        Aligned::new().with_low(0xA).with_high(0x5).into_bytes(),
+        [0x5A]
+    );
+}
+
+#[test]
+fn cfg_attr_repr_detection() {
+    #[bitfield]
+    #[cfg_attr(all(), cfg_attr(test, repr(align(4))))]
+    pub struct Aligned {
+        low: B4,
+        high: B4,
+    }
+
+    // Mentions `repr` only in a string and thus stays `#[repr(transparent)]`.
+    #[bitfield]
+    #[cfg_attr(test, doc = "Not a repr(align(4)) struct.")]
+    pub struct Word {
+        value: B32,
+    }
+
+    assert_eq!(core::mem::align_of::<Aligned>(), 4);
+    assert_eq!(core::mem::align_of::<Word>(), 1);
+    // SAFETY: `Word` is `#[repr(transparent)]` over `[u8; 4]`.
+    let word = unsafe { core::mem::transmute::<[u8; 4], Word>([0x78, 0x56, 0x34, 0x12]) };
+    assert_eq!(word.value(), 0x1234_5678);
+}
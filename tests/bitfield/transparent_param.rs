@@ -0,0 +1,39 @@
+//! Tests for `transparent = true` #[bitfield] parameter
+
+use modular_bitfield::prelude::*;
+
+#[test]
+fn transparent_conversions() {
+    use modular_bitfield::error::OutOfBounds;
+
+    #[derive(Specifier, Debug, PartialEq, Clone, Copy)]
+    enum Mode {
+        Sleep,
+        Idle,
+        Busy,
+        Off,
+    }
+
+    #[bitfield(filled = false, transparent = true)]
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    struct ModeReg {
+        mode: Mode,
+    }
+
+    #[bitfield(filled = false, transparent = true)]
+    struct Counter(B12);
+
+    let reg = ModeReg::try_from(Mode::Busy).unwrap();
+    assert_eq!(reg.mode(), Mode::Busy);
+    assert_eq!(reg, ModeReg::new().with_mode(Mode::Busy));
+    let mode: Mode = reg.into();
+    assert_eq!(mode, Mode::Busy);
+    for mode in [Mode::Sleep, Mode::Idle, Mode::Busy, Mode::Off] {
+        assert_eq!(Mode::from(ModeReg::try_from(mode).unwrap()), mode);
+    }
+
+    let counter = Counter::try_from(0x0FFF).unwrap();
+    assert_eq!(counter.get_0(), 0x0FFF);
+    assert_eq!(u16::from(counter), 0x0FFF);
+    assert!(matches!(Counter::try_from(0x1000), Err(OutOfBounds)));
+}
@@ -0,0 +1,17 @@
+//! Tests for `warn_waste` #[bitfield] parameter
+
+#[test]
+fn warn_waste_without_waste() {
+    #[deny(deprecated)]
+    mod layouts {
+        use modular_bitfield::prelude::*;
+
+        #[bitfield(warn_waste)]
+        pub struct Compact {
+            pub a: B12,
+            pub b: B4,
+        }
+    }
+
+    assert_eq!(core::mem::size_of::<layouts::Compact>(), 2);
+}
@@ -37,3 +37,21 @@ fn invalid_access_d() {
     let mut bytes = EdgeCaseBytes::new();
     bytes.set_d(0b0001_0000_u8);
 }
+
+#[test]
+#[should_panic(expected = "bit index out of bounds")]
+fn contains_bit_out_of_bounds() {
+    let _ = EdgeCaseBytes::new().contains_bit(32);
+}
+
+#[bitfield(index)]
+pub struct Indexed {
+    a: B4,
+    b: B4,
+}
+
+#[test]
+#[should_panic(expected = "bit index out of bounds")]
+fn index_out_of_bounds() {
+    let _ = Indexed::new()[8];
+}
@@ -0,0 +1,58 @@
+//! Tests for the `track_caller` #[bitfield] parameter.
+//!
+//! The panic location is only reported to the panic hook, which is process-wide.
+//! This binary therefore contains a single test so that no other test can panic
+//! or replace the hook concurrently.
+
+#![allow(dead_code)]
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(track_caller)]
+pub struct TrackCaller {
+    a: B3,
+    b: B5,
+}
+
+/// Runs `f` and returns the panic message and the line of the panic location.
+fn catch_panic(f: impl FnOnce() + std::panic::UnwindSafe) -> (String, u32) {
+    use std::cell::Cell;
+    std::thread_local! {
+        static PANIC_LINE: Cell<Option<u32>> = const { Cell::new(None) };
+    }
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|info| {
+        PANIC_LINE.with(|line| line.set(info.location().map(|location| location.line())));
+    }));
+    let payload = std::panic::catch_unwind(f).expect_err("missing panic");
+    std::panic::set_hook(previous_hook);
+    let message = payload
+        .downcast_ref::<String>()
+        .cloned()
+        .or_else(|| {
+            payload
+                .downcast_ref::<&str>()
+                .map(|message| (*message).to_owned())
+        })
+        .expect("missing panic message");
+    let line = PANIC_LINE.with(Cell::take).expect("missing panic location");
+    (message, line)
+}
+
+#[test]
+fn track_caller_reports_call_site() {
+    let expected = line!() + 1;
+    let (message, line) = catch_panic(|| TrackCaller::new().set_a(0b1000));
+    assert_eq!(
+        message,
+        "value out of bounds for field TrackCaller.a: OutOfBounds"
+    );
+    assert_eq!(line, expected);
+    let expected = line!() + 1;
+    let (message, line) = catch_panic(|| drop(TrackCaller::new().with_b(0b10_0000)));
+    assert_eq!(
+        message,
+        "value out of bounds for field TrackCaller.b: OutOfBounds"
+    );
+    assert_eq!(line, expected);
+}
@@ -5,4 +5,5 @@
 fn ui_trybuild() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/ui/**/*.rs");
+    t.pass("tests/ui_pass/*.rs");
 }
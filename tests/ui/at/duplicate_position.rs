@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Duplicate {
+    #[at(bit = 0)]
+    a: B4,
+    #[at(bit = 0)]
+    b: B4,
+}
+
+fn main() {}
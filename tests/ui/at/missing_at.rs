@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Missing {
+    #[at(bit = 0)]
+    a: B4,
+    b: B4,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct NotAtZero {
+    #[at(bit = 4)]
+    a: B4,
+    #[at(bit = 8)]
+    b: B4,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Overlapping {
+    #[at(bit = 0)]
+    a: B4,
+    #[at(bit = 3)]
+    b: B4,
+    #[at(bit = 8)]
+    c: B8,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Uncovered {
+    #[at(bit = 0)]
+    a: B3,
+    #[at(bit = 4)]
+    b: B5,
+}
+
+fn main() {}
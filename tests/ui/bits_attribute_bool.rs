@@ -0,0 +1,17 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct BoolWithTooManyBits {
+    #[bits = 2]
+    flag: bool,
+    reserved: B6,
+}
+
+#[bitfield]
+pub struct QualifiedBool {
+    #[bits = 8]
+    flag: ::core::primitive::bool,
+    reserved: B7,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+// 20 bits round up to 3 bytes which conflicts with `bytes = 2`.
+#[bitfield(bits = 20, bytes = 2)]
+#[derive(Specifier)]
+pub struct Header {
+    a: B4,
+    b: B16,
+}
+
+fn main() {}
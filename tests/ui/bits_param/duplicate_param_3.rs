@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 16..=32, bits = 16)]
+pub struct Header {
+    kind: B8,
+    length: B8,
+}
+
+fn main() {}
@@ -1,8 +1,6 @@
 use modular_bitfield::prelude::*;
 
-const NOT_LITERAL: u32 = 32;
-
-#[bitfield(bits = NOT_LITERAL)]
+#[bitfield(bits = "32")]
 pub struct SignInteger {
     sign: bool,
     value: B31,
@@ -0,0 +1,16 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 16..=32)]
+pub struct Header {
+    kind: B4,
+    flags: B4,
+}
+
+#[bitfield(bits = 8..16)]
+pub struct Extended {
+    kind: B4,
+    flags: B4,
+    length: B8,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(copy)]
+#[derive(Debug, Clone)]
+pub struct Register {
+    enabled: bool,
+    mode: B7,
+}
+
+fn main() {}
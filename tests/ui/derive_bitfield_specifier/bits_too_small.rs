@@ -0,0 +1,15 @@
+// The compile error for an out of range discriminant names the variant,
+// its discriminant and the bit width.
+
+use modular_bitfield::prelude::*;
+
+#[derive(Specifier)]
+#[bits = 2]
+pub enum Mode {
+    A,
+    B = 2,
+    C,
+    D,
+}
+
+fn main() {}
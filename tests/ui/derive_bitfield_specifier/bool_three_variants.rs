@@ -0,0 +1,13 @@
+use modular_bitfield::prelude::*;
+
+// `#[specifier(bool)]` requires exactly two unit variants.
+#[derive(Specifier)]
+#[specifier(bool)]
+#[bits = 2]
+enum Level {
+    Low,
+    Mid,
+    High,
+}
+
+fn main() {}
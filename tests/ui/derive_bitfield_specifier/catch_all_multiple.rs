@@ -0,0 +1,14 @@
+use modular_bitfield::prelude::*;
+
+#[derive(Specifier)]
+#[bits = 2]
+pub enum Version {
+    V1,
+    V2,
+    #[catch_all]
+    Unknown(u8),
+    #[catch_all]
+    Other(u8),
+}
+
+fn main() {}
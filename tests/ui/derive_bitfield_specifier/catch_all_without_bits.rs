@@ -0,0 +1,13 @@
+use modular_bitfield::prelude::*;
+
+// A `#[catch_all]` variant requires an explicit bit width.
+#[derive(Specifier)]
+pub enum Version {
+    V1,
+    V2,
+    V3,
+    #[catch_all]
+    Unknown(u8),
+}
+
+fn main() {}
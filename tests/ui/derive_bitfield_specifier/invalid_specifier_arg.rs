@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[derive(Specifier)]
+#[specifier(octal)]
+pub enum Level {
+    L0,
+    L1,
+}
+
+fn main() {}
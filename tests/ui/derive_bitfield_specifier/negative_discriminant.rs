@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[derive(Specifier)]
+#[repr(i8)]
+#[bits = 2]
+pub enum Delta {
+    Minus = -1,
+    Zero = 0,
+    Plus = 1,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+// The `#[bits = N]` of an enum must fit into its `#[repr(..)]` integer type.
+
+use modular_bitfield::prelude::*;
+
+#[derive(Specifier)]
+#[repr(u8)]
+#[bits = 9]
+pub enum Literal {
+    A = 0,
+    B = 255,
+}
+
+#[derive(Specifier)]
+#[repr(u8)]
+#[bits(<B9 as Specifier>::BITS)]
+pub enum Expression {
+    A = 0,
+    B = 255,
+}
+
+fn main() {}
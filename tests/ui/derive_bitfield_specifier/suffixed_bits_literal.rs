@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[derive(Specifier)]
+#[bits(3u8)]
+pub enum Mode {
+    A,
+    B,
+}
+
+fn main() {}
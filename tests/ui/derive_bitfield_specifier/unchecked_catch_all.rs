@@ -0,0 +1,13 @@
+use modular_bitfield::prelude::*;
+
+#[derive(Specifier)]
+#[specifier(unchecked)]
+#[bits = 2]
+pub enum Version {
+    V1,
+    V2,
+    #[catch_all]
+    Unknown(u8),
+}
+
+fn main() {}
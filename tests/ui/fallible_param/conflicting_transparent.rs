@@ -0,0 +1,8 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(transparent = true, fallible = true)]
+pub struct Wrapper {
+    value: u8,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(fallible = true)]
+pub struct Status {
+    ready: bool,
+    code: B7,
+}
+
+fn main() {
+    let mut status = Status::new();
+    let _: bool = status.ready(); // ERROR!
+    let _ = status.ready_or_err(); // ERROR!
+    let _ = status.set_code_checked(1); // ERROR!
+    let _ = status.with_code_checked(1); // ERROR!
+}
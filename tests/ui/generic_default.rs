@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+struct Packet<Payload: Specifier = B32> {
+    header: B8,
+    body: Payload,
+}
+
+fn main() {}
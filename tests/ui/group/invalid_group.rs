@@ -0,0 +1,30 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct NonAdjacent {
+    #[group(Pair)]
+    a: B4,
+    b: B4,
+    #[group(Pair)]
+    c: B8,
+}
+
+#[bitfield]
+pub struct Tuple(#[group(Pair)] B4, #[group(Pair)] B4);
+
+#[bitfield(fallible = true)]
+pub struct Fallible {
+    #[group(Pair)]
+    a: B4,
+    #[group(Pair)]
+    b: B4,
+}
+
+#[bitfield]
+pub struct InvalidName {
+    #[group("Pair")]
+    a: B4,
+    b: B4,
+}
+
+fn main() {}
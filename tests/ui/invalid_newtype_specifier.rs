@@ -0,0 +1,6 @@
+use modular_bitfield::prelude::*;
+
+#[derive(Specifier)]
+pub struct InvalidNewtypeSpecifier(u8, u8);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(pad_to_pow2, bits = 24)]
+pub struct Descriptor {
+    kind: B4,
+    length: B16,
+}
+
+fn main() {}
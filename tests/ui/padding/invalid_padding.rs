@@ -0,0 +1,31 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct NonUnitPadding {
+    #[padding(4)]
+    reserved: B4,
+    a: B4,
+}
+
+#[bitfield]
+pub struct MissingPadding {
+    reserved: (),
+    a: u8,
+}
+
+#[bitfield]
+pub struct ZeroPadding {
+    #[padding(0)]
+    reserved: (),
+    a: u8,
+}
+
+#[bitfield]
+pub struct DuplicatePadding {
+    #[padding(4)]
+    #[padding(4)]
+    reserved: (),
+    a: B4,
+}
+
+fn main() {}
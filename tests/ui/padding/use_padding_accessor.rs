@@ -0,0 +1,13 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Padded {
+    a: B4,
+    #[padding(4)]
+    reserved: (),
+}
+
+fn main() {
+    let padded = Padded::new();
+    let _ = padded.reserved(); // ERROR!
+}
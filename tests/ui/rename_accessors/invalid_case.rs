@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(rename_accessors = "kebab")]
+pub struct Header {
+    a: B4,
+    b: B4,
+}
+
+fn main() {}
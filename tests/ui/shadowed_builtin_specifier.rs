@@ -0,0 +1,17 @@
+use modular_bitfield::prelude::*;
+
+#[derive(Specifier, Debug, PartialEq)]
+pub enum B2 {
+    Zero,
+    One,
+    Two,
+    Three,
+}
+
+#[bitfield]
+pub struct Register {
+    mode: B2,
+    rest: B6,
+}
+
+fn main() {}
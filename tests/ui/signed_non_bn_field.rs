@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Sample {
+    #[signed]
+    delta: u8,
+    #[signed]
+    flag: bool,
+    reserved: B7,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(setters = false)]
+pub struct Status {
+    ready: bool,
+    code: B7,
+}
+
+fn main() {
+    let status = Status::new();
+    assert!(!status.ready());
+    let _ = status.with_code(1); // ERROR!
+}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+// A transparent bitfield struct must wrap exactly one field.
+#[bitfield(transparent = true)]
+pub struct Base {
+    a: B2,
+    b: B6,
+}
+
+fn main() {}
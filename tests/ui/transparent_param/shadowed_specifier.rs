@@ -0,0 +1,14 @@
+use modular_bitfield::prelude::*;
+
+// A user defined type that shadows a predefined specifier.
+#[derive(Specifier)]
+#[allow(non_camel_case_types)]
+pub enum B12 {
+    Low,
+    High,
+}
+
+#[bitfield(filled = false, transparent = true)]
+pub struct Level(B12);
+
+fn main() {}
@@ -0,0 +1,12 @@
+#![deny(deprecated)]
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(filled = false, warn_waste)]
+pub struct Wasteful {
+    a: B8,
+    b: B8,
+    c: bool,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use modular_bitfield::prelude::*;
+
+// Rust's `#[expect(lint)]` attribute is not mistaken for `#[expect_value = value]`.
+#[bitfield]
+pub struct Header {
+    #[expect(clippy::must_use_candidate)]
+    #[expect_value = 2]
+    version: B4,
+    kind: B4,
+}
+
+fn main() {
+    let header = Header::new().with_version(2).with_kind(1);
+    assert!(header.is_well_formed());
+}